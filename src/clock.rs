@@ -0,0 +1,172 @@
+//! Sources of the current time
+//!
+//! Functions which depend on the current time have `_with` variants that take a
+//! [`Clock`](trait.Clock.html), so that time dependent logic can be tested
+//! deterministically with a [`FixedClock`](struct.FixedClock.html)
+use crate::Seconds;
+use std::{cell::Cell, fmt, time::Duration};
+
+/// A source of the current time
+pub trait Clock {
+    /// return the current time in seconds since the unix epoch
+    fn now(&self) -> Seconds;
+}
+
+/// The system's wall clock, as read by [`Seconds::now`](struct.Seconds.html#method.now)
+#[derive(Debug, Default, Copy, Clone)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Seconds {
+        Seconds::now()
+    }
+}
+
+/// A clock which always reports the same instant until told otherwise
+#[derive(Debug, Clone)]
+pub struct FixedClock(Cell<Seconds>);
+
+impl FixedClock {
+    /// create a clock frozen at `now`
+    pub fn new(now: Seconds) -> Self {
+        FixedClock(Cell::new(now))
+    }
+
+    /// move the clock to `now`
+    pub fn set(
+        &self,
+        now: Seconds,
+    ) {
+        self.0.set(now)
+    }
+
+    /// move the clock forward by `dur`
+    pub fn advance(
+        &self,
+        dur: Duration,
+    ) {
+        self.0.set(self.0.get() + dur)
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> Seconds {
+        self.0.get()
+    }
+}
+
+/// A clock which reports the time of another clock skewed by a fixed offset,
+/// simulating a node whose clock runs fast or slow
+pub struct OffsetClock {
+    inner: Box<dyn Clock>,
+    offset: Duration,
+    ahead: bool,
+}
+
+impl OffsetClock {
+    /// create a clock reporting `offset` later than `inner`
+    pub fn ahead(
+        inner: Box<dyn Clock>,
+        offset: Duration,
+    ) -> Self {
+        OffsetClock {
+            inner,
+            offset,
+            ahead: true,
+        }
+    }
+
+    /// create a clock reporting `offset` earlier than `inner`
+    pub fn behind(
+        inner: Box<dyn Clock>,
+        offset: Duration,
+    ) -> Self {
+        OffsetClock {
+            inner,
+            offset,
+            ahead: false,
+        }
+    }
+
+    /// return how far this clock is skewed from its inner clock
+    pub fn offset(&self) -> Duration {
+        self.offset
+    }
+
+    /// return true if this clock runs ahead of its inner clock, false if behind
+    pub fn is_ahead(&self) -> bool {
+        self.ahead
+    }
+}
+
+impl fmt::Debug for OffsetClock {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        f.debug_struct("OffsetClock")
+            .field("offset", &self.offset)
+            .field("ahead", &self.ahead)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Clock for OffsetClock {
+    fn now(&self) -> Seconds {
+        if self.ahead {
+            self.inner.now() + self.offset
+        } else {
+            self.inner.now() - self.offset
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Clock, FixedClock, OffsetClock, SystemClock};
+    use crate::Seconds;
+    use std::time::Duration;
+
+    #[test]
+    fn system_clock_is_now() {
+        let (clock, now) = (SystemClock.now(), Seconds::now());
+        assert!((now.0 - clock.0).abs() < 1.0);
+    }
+
+    #[test]
+    fn fixed_clock_is_fixed() {
+        let clock = FixedClock::new(Seconds(1.5));
+        assert_eq!(clock.now(), Seconds(1.5));
+        clock.set(Seconds(2.5));
+        assert_eq!(clock.now(), Seconds(2.5));
+        clock.advance(Duration::from_millis(500));
+        assert_eq!(clock.now(), Seconds(3.0));
+    }
+
+    #[test]
+    fn offset_clock_is_skewed() {
+        let fast = OffsetClock::ahead(
+            Box::new(FixedClock::new(Seconds(100.0))),
+            Duration::from_secs(5),
+        );
+        assert_eq!(fast.now(), Seconds(105.0));
+        let slow = OffsetClock::behind(
+            Box::new(FixedClock::new(Seconds(100.0))),
+            Duration::from_millis(2_500),
+        );
+        assert_eq!(slow.now(), Seconds(97.5));
+    }
+
+    #[test]
+    fn offset_clock_exposes_skew() {
+        let fast = OffsetClock::ahead(Box::new(SystemClock), Duration::from_secs(5));
+        assert_eq!(fast.offset(), Duration::from_secs(5));
+        assert!(fast.is_ahead());
+        let slow = OffsetClock::behind(Box::new(SystemClock), Duration::from_secs(5));
+        assert!(!slow.is_ahead());
+        assert_eq!(
+            format!("{:?}", fast),
+            "OffsetClock { offset: 5s, ahead: true, .. }"
+        );
+    }
+}
@@ -0,0 +1,1263 @@
+//! UTC calendar support, enabled with the `date` feature
+//!
+//! Conversions between epoch days and civil dates use Howard Hinnant's
+//! [days from civil](http://howardhinnant.github.io/date_algorithms.html) algorithms,
+//! which are valid for the proleptic Gregorian calendar in both directions from
+//! the epoch, without depending on a timezone database
+use crate::{fits_i64, ParseError, Seconds};
+use std::{convert::TryFrom, error::Error, fmt, fmt::Write};
+
+const SECS_PER_DAY: i64 = 86_400;
+const MICROS_PER_SEC: i64 = 1_000_000;
+const MAX_OFFSET_SECS: i32 = 14 * 3600;
+/// the longest span, in days, `business_days_between` looks up holidays over,
+/// about 2700 years
+const MAX_HOLIDAY_SPAN_DAYS: i64 = 1_000_000;
+const DOS_YEAR_BASE: i64 = 1980;
+const DOS_MAX_YEAR: i64 = DOS_YEAR_BASE + 127;
+
+const WEEKDAYS: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+const MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+const LONG_WEEKDAYS: [&str; 7] = [
+    "Sunday",
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+];
+
+/// days since the epoch for a given proleptic Gregorian year, month (1-12) and day (1-31)
+pub(crate) fn days_from_civil(
+    year: i64,
+    month: u32,
+    day: u32,
+) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year.rem_euclid(400);
+    let (month, day) = (i64::from(month), i64::from(day));
+    let doy = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+/// the proleptic Gregorian year, month (1-12) and day (1-31) for days since the epoch
+pub(crate) fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+/// true for years in the proleptic Gregorian calendar with a February 29th
+pub(crate) fn is_leap_year(year: i64) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+/// the number of days in a given year and month (1-12)
+pub(crate) fn days_in_month(
+    year: i64,
+    month: u32,
+) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// parse a fixed width run of ascii digits
+fn digits(
+    bytes: &[u8],
+    start: usize,
+    len: usize,
+) -> Option<u32> {
+    let run = bytes.get(start..start + len)?;
+    run.iter().try_fold(0, |acc, b| match b {
+        b'0'..=b'9' => Some(acc * 10 + u32::from(b - b'0')),
+        _ => None,
+    })
+}
+
+/// parse an RFC 3339 timestamp such as `2018-12-18T12:32:22.711932Z`
+fn parse_rfc3339(s: &str) -> Option<Seconds> {
+    let bytes = s.as_bytes();
+    let year = digits(bytes, 0, 4)?;
+    let month = digits(bytes, 5, 2)?;
+    let day = digits(bytes, 8, 2)?;
+    let hour = digits(bytes, 11, 2)?;
+    let minute = digits(bytes, 14, 2)?;
+    let second = digits(bytes, 17, 2)?;
+    if bytes[4] != b'-'
+        || bytes[7] != b'-'
+        || !matches!(bytes[10], b'T' | b't' | b' ')
+        || bytes[13] != b':'
+        || bytes[16] != b':'
+    {
+        return None;
+    }
+    let year = i64::from(year);
+    // a second of 60 allows for leap seconds, which POSIX time folds into the
+    // following second
+    if !(1..=12).contains(&month)
+        || !(1..=days_in_month(year, month)).contains(&day)
+        || hour > 23
+        || minute > 59
+        || second > 60
+    {
+        return None;
+    }
+
+    let mut rest = &s[19..];
+    let mut fract = 0.0;
+    if rest.starts_with('.') {
+        let len = rest[1..].bytes().take_while(u8::is_ascii_digit).count();
+        if len == 0 {
+            return None;
+        }
+        fract = rest[..=len].parse::<f64>().ok()?;
+        rest = &rest[len + 1..];
+    }
+
+    let offset = match rest {
+        "Z" | "z" => 0,
+        _ => {
+            let bytes = rest.as_bytes();
+            if bytes.len() != 6 || bytes[3] != b':' {
+                return None;
+            }
+            let sign = match bytes[0] {
+                b'+' => 1,
+                b'-' => -1,
+                _ => return None,
+            };
+            let (hours, minutes) = (digits(bytes, 1, 2)?, digits(bytes, 4, 2)?);
+            if hours > 23 || minutes > 59 {
+                return None;
+            }
+            sign * i64::from(hours * 3600 + minutes * 60)
+        }
+    };
+
+    let whole = days_from_civil(year, month, day) * SECS_PER_DAY
+        + i64::from(hour * 3600 + minute * 60 + second)
+        - offset;
+    Some(Seconds(whole as f64 + fract))
+}
+
+/// parse a run of one to `max` ascii digits making up the whole of `s`
+fn number(
+    s: &str,
+    max: usize,
+) -> Option<u32> {
+    if s.is_empty() || s.len() > max {
+        return None;
+    }
+    digits(s.as_bytes(), 0, s.len())
+}
+
+/// parse an `HH:MM:SS` time of day
+fn parse_hms(s: &str) -> Option<u32> {
+    let bytes = s.as_bytes();
+    if bytes.len() != 8 || bytes[2] != b':' || bytes[5] != b':' {
+        return None;
+    }
+    let (hour, minute, second) = (
+        digits(bytes, 0, 2)?,
+        digits(bytes, 3, 2)?,
+        digits(bytes, 6, 2)?,
+    );
+    if hour > 23 || minute > 59 || second > 60 {
+        return None;
+    }
+    Some(hour * 3600 + minute * 60 + second)
+}
+
+/// parse an HTTP date in any of the three formats RFC 7231 requires recipients to
+/// accept, always in GMT
+fn parse_http_date(s: &str) -> Option<Seconds> {
+    let tokens: Vec<_> = s.split_whitespace().collect();
+    let (year, month, day, time) = match tokens[..] {
+        // IMF-fixdate, e.g. Sun, 06 Nov 1994 08:49:37 GMT
+        [weekday, day, month, year, time, "GMT"] => {
+            if !WEEKDAYS.contains(&weekday.strip_suffix(',')?) || day.len() != 2 || year.len() != 4
+            {
+                return None;
+            }
+            (i64::from(number(year, 4)?), month, number(day, 2)?, time)
+        }
+        // obsolete RFC 850, e.g. Sunday, 06-Nov-94 08:49:37 GMT
+        [weekday, date, time, "GMT"] => {
+            if !LONG_WEEKDAYS.contains(&weekday.strip_suffix(',')?) {
+                return None;
+            }
+            let mut parts = date.split('-');
+            let (day, month, year) = (parts.next()?, parts.next()?, parts.next()?);
+            if parts.next().is_some() || day.len() != 2 || year.len() != 2 {
+                return None;
+            }
+            // two digit years pivot at 1970 rather than relative to the current date
+            let year = i64::from(number(year, 2)?);
+            let year = if year < 70 { 2000 + year } else { 1900 + year };
+            (year, month, number(day, 2)?, time)
+        }
+        // ANSI C asctime, e.g. Sun Nov  6 08:49:37 1994
+        [weekday, month, day, time, year] => {
+            if !WEEKDAYS.contains(&weekday) || year.len() != 4 {
+                return None;
+            }
+            (i64::from(number(year, 4)?), month, number(day, 2)?, time)
+        }
+        _ => return None,
+    };
+    let month = MONTHS.iter().position(|&name| name == month)? as u32 + 1;
+    if !(1..=days_in_month(year, month)).contains(&day) {
+        return None;
+    }
+    let whole = days_from_civil(year, month, day) * SECS_PER_DAY + i64::from(parse_hms(time)?);
+    Some(Seconds(whole as f64))
+}
+
+/// parse `now` optionally followed by a signed offset with a unit, such as `now-1h`
+fn parse_relative_to_now(
+    s: &str,
+    now: Seconds,
+) -> Option<Seconds> {
+    let rest = s.strip_prefix("now")?;
+    if rest.is_empty() {
+        return Some(now);
+    }
+    let sign = match rest.as_bytes()[0] {
+        b'+' => 1.0,
+        b'-' => -1.0,
+        _ => return None,
+    };
+    let offset = &rest[1..];
+    let (number, unit) = [
+        ("ms", 1.0e-3),
+        ("s", 1.0),
+        ("m", 60.0),
+        ("h", 3_600.0),
+        ("d", 86_400.0),
+        ("w", 604_800.0),
+    ]
+    .iter()
+    .find_map(|&(suffix, unit)| offset.strip_suffix(suffix).map(|number| (number, unit)))?;
+    // signs belong before `now`'s offset, not the number
+    if !number.starts_with(|c: char| c.is_ascii_digit()) {
+        return None;
+    }
+    // a finite number can still overflow once scaled by its unit
+    let secs = Seconds(now.0 + sign * number.parse::<f64>().ok()? * unit);
+    if secs.is_finite() {
+        Some(secs)
+    } else {
+        None
+    }
+}
+
+/// day of the week for days since the epoch, where 0 is Sunday
+fn weekday(days: i64) -> u32 {
+    // the epoch fell on a Thursday
+    (days + 4).rem_euclid(7) as u32
+}
+
+/// days since the epoch, treating non-finite seconds as the epoch itself and
+/// saturating at the days reachable with an `i64` of seconds, so the calendar
+/// arithmetic on the result can't overflow
+fn calendar_day(seconds: Seconds) -> i64 {
+    const MAX_DAY: i64 = i64::MAX / SECS_PER_DAY;
+    if seconds.is_finite() {
+        seconds.epoch_day().clamp(-MAX_DAY, MAX_DAY)
+    } else {
+        0
+    }
+}
+
+/// day of the year for days since the epoch, where 1 is January 1st
+fn ordinal(days: i64) -> u32 {
+    let (year, _, _) = civil_from_days(days);
+    (days - days_from_civil(year, 1, 1) + 1) as u32
+}
+
+/// true for finite seconds whose whole seconds fit in an `i64`
+fn has_calendar_date(seconds: Seconds) -> bool {
+    fits_i64(seconds.0.floor())
+}
+
+/// A UTC calendar breakdown of a timestamp at microsecond precision
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub(crate) struct DateTime {
+    pub(crate) year: i64,
+    pub(crate) month: u32,
+    pub(crate) day: u32,
+    pub(crate) hour: u32,
+    pub(crate) minute: u32,
+    pub(crate) second: u32,
+    pub(crate) micros: u32,
+    /// days since the epoch
+    pub(crate) days: i64,
+}
+
+impl DateTime {
+    /// returns `None` for non-finite seconds and whole seconds beyond the range
+    /// of an `i64`
+    pub(crate) fn from_seconds(seconds: Seconds) -> Option<Self> {
+        if !has_calendar_date(seconds) {
+            return None;
+        }
+        let whole = seconds.0.floor();
+        // whole seconds are split off before scaling so that the micros can't
+        // overflow, and rounding to whole micros keeps float noise out of the fraction
+        let mut secs = whole as i64;
+        let mut micros = ((seconds.0 - whole) * 1.0e6).round() as i64;
+        if micros >= MICROS_PER_SEC {
+            secs += 1;
+            micros -= MICROS_PER_SEC;
+        }
+        let days = secs.div_euclid(SECS_PER_DAY);
+        let secs_of_day = secs.rem_euclid(SECS_PER_DAY) as u32;
+        let (year, month, day) = civil_from_days(days);
+        Some(DateTime {
+            year,
+            month,
+            day,
+            hour: secs_of_day / 3600,
+            minute: secs_of_day % 3600 / 60,
+            second: secs_of_day % 60,
+            micros: micros as u32,
+            days,
+        })
+    }
+
+    /// the date and time portion of an RFC 3339 timestamp, without an offset
+    fn rfc3339_local(&self) -> String {
+        format!(
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:06}",
+            self.year, self.month, self.day, self.hour, self.minute, self.second, self.micros
+        )
+    }
+
+    /// whole seconds since the epoch
+    pub(crate) fn unix_secs(&self) -> i64 {
+        self.days * SECS_PER_DAY + i64::from(self.hour * 3600 + self.minute * 60 + self.second)
+    }
+}
+
+/// Reasons a timestamp can fail to format
+#[derive(Debug, PartialEq, Clone)]
+pub enum FormatError {
+    /// the pattern contained a `%` specifier which is not supported
+    UnknownSpecifier(char),
+    /// the pattern ended with an unterminated `%`
+    Incomplete,
+    /// the timestamp was NaN or infinite and has no calendar representation
+    NonFinite,
+    /// the timestamp's whole seconds were beyond the range of an `i64`
+    OutOfRange,
+}
+
+impl fmt::Display for FormatError {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter,
+    ) -> fmt::Result {
+        match self {
+            FormatError::UnknownSpecifier(c) => write!(f, "unknown format specifier %{}", c),
+            FormatError::Incomplete => {
+                f.write_str("format pattern ends with an incomplete specifier")
+            }
+            FormatError::NonFinite => f.write_str("cannot format non-finite seconds"),
+            FormatError::OutOfRange => {
+                f.write_str("cannot format seconds beyond the range of an i64")
+            }
+        }
+    }
+}
+
+impl Error for FormatError {}
+
+impl Seconds {
+    /// return the UTC day of the week, where 0 is Sunday and 6 is Saturday
+    ///
+    /// Non-finite seconds have no calendar date and are treated as the epoch
+    #[must_use]
+    pub fn weekday_utc(&self) -> u8 {
+        weekday(calendar_day(*self)) as u8
+    }
+
+    /// return the UTC day of the year, where 1 is January 1st
+    ///
+    /// Non-finite seconds have no calendar date and are treated as the epoch
+    #[must_use]
+    pub fn day_of_year_utc(&self) -> u16 {
+        ordinal(calendar_day(*self)) as u16
+    }
+
+    /// return the ISO 8601 week numbering year and week, from 1 to 53, in UTC
+    ///
+    /// ISO weeks start on Monday and week 1 is the week containing the year's first
+    /// Thursday, so the first days of January may fall in the last week of the
+    /// previous year and the last days of December in week 1 of the next. Years
+    /// beyond the range of an `i32` saturate. Non-finite seconds have no calendar
+    /// date and are treated as the epoch
+    #[must_use]
+    pub fn iso_week_utc(&self) -> (i32, u8) {
+        let days = calendar_day(*self);
+        // the thursday of the same monday to sunday week decides the iso year
+        let iso_weekday = (days + 3).rem_euclid(7) + 1;
+        let thursday = days - iso_weekday + 4;
+        let (year, _, _) = civil_from_days(thursday);
+        let year = i32::try_from(year).unwrap_or(if year < 0 { i32::MIN } else { i32::MAX });
+        (year, ((ordinal(thursday) - 1) / 7 + 1) as u8)
+    }
+
+    /// return the first instant of the UTC calendar month these seconds fall in
+    ///
+    /// Non-finite seconds, and those beyond the range of an `i64`, have no calendar
+    /// date and are returned unchanged
+    pub fn start_of_month_utc(&self) -> Self {
+        if !has_calendar_date(*self) {
+            return *self;
+        }
+        let (year, month, _) = civil_from_days(self.epoch_day());
+        // the month may start before the earliest i64 of seconds
+        Seconds(days_from_civil(year, month, 1) as f64 * SECS_PER_DAY as f64)
+    }
+
+    /// return the first instant of the UTC calendar year these seconds fall in
+    ///
+    /// Non-finite seconds, and those beyond the range of an `i64`, have no calendar
+    /// date and are returned unchanged
+    pub fn start_of_year_utc(&self) -> Self {
+        if !has_calendar_date(*self) {
+            return *self;
+        }
+        let (year, _, _) = civil_from_days(self.epoch_day());
+        Seconds(days_from_civil(year, 1, 1) as f64 * SECS_PER_DAY as f64)
+    }
+
+    /// move these seconds by a number of UTC calendar months, keeping the time of day
+    ///
+    /// When the target month is too short for the day of the month it is clamped to
+    /// the target month's last day, so January 31st plus one month is February 28th
+    /// or 29th. Non-finite seconds, and those beyond the range of an `i64`, have no
+    /// calendar date and are returned unchanged
+    pub fn add_months_utc(
+        &self,
+        months: i32,
+    ) -> Self {
+        if !has_calendar_date(*self) {
+            return *self;
+        }
+        let days = self.epoch_day();
+        // days are scaled back to seconds in f64, as the target month may be beyond
+        // the range of an i64 of seconds
+        let time_of_day = self.0 - days as f64 * SECS_PER_DAY as f64;
+        let (year, month, day) = civil_from_days(days);
+        let total = year * 12 + i64::from(month - 1) + i64::from(months);
+        let (year, month) = (total.div_euclid(12), total.rem_euclid(12) as u32 + 1);
+        let day = day.min(days_in_month(year, month));
+        Seconds(days_from_civil(year, month, day) as f64 * SECS_PER_DAY as f64 + time_of_day)
+    }
+
+    /// return the number of UTC business days from the day of `self` up to, but not
+    /// including, the day of `other`
+    ///
+    /// Saturdays, Sundays and any day for which `is_holiday` returns true, given its
+    /// index of days since the epoch, are skipped. The result is negative when
+    /// `other` is earlier than `self`
+    ///
+    /// `is_holiday` is called once for each weekday in the span, so spans longer
+    /// than 1,000,000 days, about 2700 years, return `None` rather than a count
+    /// which ignores holidays. Non-finite seconds also return `None`
+    #[must_use]
+    pub fn business_days_between<F>(
+        &self,
+        other: Seconds,
+        is_holiday: F,
+    ) -> Option<i64>
+    where
+        F: Fn(i64) -> bool,
+    {
+        if !self.is_finite() || !other.is_finite() {
+            return None;
+        }
+        let (from, to) = (calendar_day(*self), calendar_day(other));
+        let (lo, hi) = (from.min(to), from.max(to));
+        if hi - lo > MAX_HOLIDAY_SPAN_DAYS {
+            return None;
+        }
+        let is_weekday = |day: i64| !matches!(weekday(day), 0 | 6);
+        // every run of 7 consecutive days holds 5 weekdays, leaving under a week
+        // to walk
+        let weeks = (hi - lo) / 7;
+        let weekdays =
+            weeks * 5 + (lo + weeks * 7..hi).filter(|&day| is_weekday(day)).count() as i64;
+        let holidays = (lo..hi)
+            .filter(|&day| is_weekday(day) && is_holiday(day))
+            .count() as i64;
+        let count = weekdays - holidays;
+        Some(if to < from { -count } else { count })
+    }
+
+    /// format these seconds as a UTC date and time using a strftime style pattern
+    ///
+    /// The following specifiers are supported
+    ///
+    /// | specifier | meaning                                  | example      |
+    /// |-----------|------------------------------------------|--------------|
+    /// | `%Y`      | year                                     | `2018`       |
+    /// | `%m`      | month, zero padded                       | `12`         |
+    /// | `%d`      | day of the month, zero padded            | `18`         |
+    /// | `%H`      | hour, zero padded                        | `12`         |
+    /// | `%M`      | minute, zero padded                      | `32`         |
+    /// | `%S`      | second, zero padded                      | `22`         |
+    /// | `%f`      | microseconds, zero padded                | `711932`     |
+    /// | `%j`      | day of the year, zero padded             | `352`        |
+    /// | `%a`      | abbreviated weekday                      | `Tue`        |
+    /// | `%b`      | abbreviated month                        | `Dec`        |
+    /// | `%s`      | whole seconds since the epoch            | `1545136342` |
+    /// | `%z`      | utc offset, always `+0000`               | `+0000`      |
+    /// | `%F`      | shorthand for `%Y-%m-%d`                 | `2018-12-18` |
+    /// | `%T`      | shorthand for `%H:%M:%S`                 | `12:32:22`   |
+    /// | `%%`      | a literal `%`                            | `%`          |
+    ///
+    /// Any other specifier results in a `FormatError`
+    pub fn format_with(
+        &self,
+        pattern: &str,
+    ) -> Result<String, FormatError> {
+        let dt = DateTime::from_seconds(*self).ok_or(if self.is_finite() {
+            FormatError::OutOfRange
+        } else {
+            FormatError::NonFinite
+        })?;
+        let mut out = String::with_capacity(pattern.len() * 2);
+        let mut chars = pattern.chars();
+        while let Some(c) = chars.next() {
+            if c != '%' {
+                out.push(c);
+                continue;
+            }
+            let spec = chars.next().ok_or(FormatError::Incomplete)?;
+            // writing to a String can not fail
+            let _ = match spec {
+                'Y' => write!(out, "{:04}", dt.year),
+                'm' => write!(out, "{:02}", dt.month),
+                'd' => write!(out, "{:02}", dt.day),
+                'H' => write!(out, "{:02}", dt.hour),
+                'M' => write!(out, "{:02}", dt.minute),
+                'S' => write!(out, "{:02}", dt.second),
+                'f' => write!(out, "{:06}", dt.micros),
+                'j' => write!(out, "{:03}", ordinal(dt.days)),
+                'a' => write!(out, "{}", WEEKDAYS[weekday(dt.days) as usize]),
+                'b' => write!(out, "{}", MONTHS[dt.month as usize - 1]),
+                's' => write!(out, "{}", dt.unix_secs()),
+                'z' => write!(out, "+0000"),
+                'F' => write!(out, "{:04}-{:02}-{:02}", dt.year, dt.month, dt.day),
+                'T' => write!(out, "{:02}:{:02}:{:02}", dt.hour, dt.minute, dt.second),
+                '%' => write!(out, "%"),
+                other => return Err(FormatError::UnknownSpecifier(other)),
+            };
+        }
+        Ok(out)
+    }
+
+    /// parse an RFC 3339 timestamp such as `2018-12-18T12:32:22.711932Z`
+    ///
+    /// Both `Z` and numeric `+HH:MM`/`-HH:MM` offsets are accepted. A leap second,
+    /// `:60`, is folded into the following second as it is in POSIX time
+    pub fn from_rfc3339(s: &str) -> Result<Self, ParseError> {
+        parse_rfc3339(s).ok_or_else(|| ParseError::new(s, "rfc 3339 timestamp"))
+    }
+
+    /// parse an HTTP date, as found in `Last-Modified` or `Expires` headers
+    ///
+    /// All three formats RFC 7231 requires recipients to accept are supported, the
+    /// preferred IMF-fixdate `Sun, 06 Nov 1994 08:49:37 GMT`, the obsolete RFC 850
+    /// `Sunday, 06-Nov-94 08:49:37 GMT` and ANSI C's asctime `Sun Nov  6 08:49:37 1994`.
+    /// Two digit RFC 850 years before 70 are read as 20xx and the rest as 19xx
+    pub fn from_http_date(s: &str) -> Result<Self, ParseError> {
+        parse_http_date(s).ok_or_else(|| ParseError::new(s, "http date"))
+    }
+
+    /// parse either decimal unix seconds or an RFC 3339 timestamp
+    ///
+    /// Numeric parsing is attempted first, so any input which is a valid number,
+    /// such as `2018`, is interpreted as unix seconds rather than as a date.
+    /// Otherwise the input must be a complete RFC 3339 timestamp, see
+    /// [`Seconds::from_rfc3339`](struct.Seconds.html#method.from_rfc3339)
+    pub fn parse_unix_or_rfc3339(s: &str) -> Result<Self, ParseError> {
+        s.parse::<Seconds>()
+            .ok()
+            .or_else(|| parse_rfc3339(s))
+            .ok_or_else(|| ParseError::new(s, "unix seconds or rfc 3339 timestamp"))
+    }
+
+    /// format these seconds as an RFC 3339 UTC timestamp with microsecond precision,
+    /// such as `2018-12-18T12:32:22.711932Z`
+    ///
+    /// Non-finite seconds, and those beyond the range of an `i64`, have no calendar
+    /// representation and are formatted as they are by `Display`
+    #[must_use]
+    pub fn to_rfc3339(&self) -> String {
+        match DateTime::from_seconds(*self) {
+            Some(dt) => format!("{}Z", dt.rfc3339_local()),
+            None => self.to_string(),
+        }
+    }
+
+    /// parse a time relative to `now`, such as `now`, `now-1h` or `now+30m`, or an
+    /// absolute time as accepted by
+    /// [`Seconds::parse_unix_or_rfc3339`](struct.Seconds.html#method.parse_unix_or_rfc3339)
+    ///
+    /// Offsets are a decimal number with an `ms`, `s`, `m`, `h`, `d` or `w` unit, where
+    /// days and weeks are a fixed 86400 and 604800 seconds. `now` is taken as an
+    /// argument, rather than read from the clock, so results are reproducible
+    pub fn parse_relative(
+        s: &str,
+        now: Seconds,
+    ) -> Result<Self, ParseError> {
+        if s.starts_with("now") {
+            return parse_relative_to_now(s, now)
+                .ok_or_else(|| ParseError::new(s, "relative time"));
+        }
+        Seconds::parse_unix_or_rfc3339(s).map_err(|_| ParseError::new(s, "relative time"))
+    }
+
+    /// format these seconds as an RFC 3339 timestamp in UTC with up to nanosecond
+    /// precision, such as `2018-12-18T12:32:22.5Z`
+    ///
+    /// Trailing zeros are trimmed from the fraction and it is left out entirely on a
+    /// whole second. Note that an `f64` only holds about a quarter of a microsecond of
+    /// precision at current epochs, so the last digits reflect the float rather than
+    /// a true nanosecond measurement. Non-finite seconds, and those beyond the range
+    /// of an `i64`, are formatted as they are by `Display`
+    #[must_use]
+    pub fn to_rfc3339_nanos(&self) -> String {
+        if !has_calendar_date(*self) {
+            return self.to_string();
+        }
+        let (secs, nanos) = self.to_timestamp_parts();
+        let secs_of_day = secs.rem_euclid(SECS_PER_DAY);
+        let (year, month, day) = civil_from_days(secs.div_euclid(SECS_PER_DAY));
+        let mut out = format!(
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+            year,
+            month,
+            day,
+            secs_of_day / 3600,
+            secs_of_day % 3600 / 60,
+            secs_of_day % 60
+        );
+        if nanos > 0 {
+            let fraction = format!("{:09}", nanos);
+            out.push('.');
+            out.push_str(fraction.trim_end_matches('0'));
+        }
+        out.push('Z');
+        out
+    }
+
+    /// format these seconds as an RFC 2822 date in UTC, as used by email and legacy
+    /// HTTP headers, such as `Tue, 18 Dec 2018 12:32:22 +0000`
+    ///
+    /// Fractional seconds are truncated. Non-finite seconds, and those beyond the
+    /// range of an `i64`, have no calendar representation and are formatted as they
+    /// are by `Display`
+    #[must_use]
+    pub fn to_rfc2822(&self) -> String {
+        match DateTime::from_seconds(*self) {
+            Some(dt) => format!(
+                "{}, {:02} {} {:04} {:02}:{:02}:{:02} +0000",
+                WEEKDAYS[weekday(dt.days) as usize],
+                dt.day,
+                MONTHS[dt.month as usize - 1],
+                dt.year,
+                dt.hour,
+                dt.minute,
+                dt.second
+            ),
+            None => self.to_string(),
+        }
+    }
+
+    /// format these seconds as an RFC 3339 timestamp with microsecond precision in
+    /// the local time of a fixed UTC offset, such as `2018-12-18T14:32:22.711932+02:00`
+    ///
+    /// This shifts the wall clock time by `offset_seconds` east of UTC and appends
+    /// the offset, without consulting a timezone database. Non-finite seconds, and
+    /// those beyond the range of an `i64`, are formatted as they are by `Display`
+    ///
+    /// # Panics
+    ///
+    /// Panics if the offset is more than 14 hours either side of UTC
+    #[must_use]
+    pub fn to_rfc3339_with_offset(
+        &self,
+        offset_seconds: i32,
+    ) -> String {
+        assert!(
+            (-MAX_OFFSET_SECS..=MAX_OFFSET_SECS).contains(&offset_seconds),
+            "utc offset {}s is outside of +/-14 hours",
+            offset_seconds
+        );
+        let shifted = Seconds(self.0 + f64::from(offset_seconds));
+        match DateTime::from_seconds(shifted) {
+            Some(dt) => {
+                let sign = if offset_seconds < 0 { '-' } else { '+' };
+                let offset = offset_seconds.unsigned_abs();
+                format!(
+                    "{}{}{:02}:{:02}",
+                    dt.rfc3339_local(),
+                    sign,
+                    offset / 3600,
+                    offset % 3600 / 60
+                )
+            }
+            None => self.to_string(),
+        }
+    }
+
+    /// create seconds from an MS-DOS packed date and time, as stored in ZIP archive
+    /// entries, interpreted as UTC
+    ///
+    /// The date packs the years since 1980, month and day as `yyyyyyym mmmddddd` and
+    /// the time packs the hour, minute and seconds halved as `hhhhhmmm mmmsssss`.
+    /// Months outside of 1-12 are clamped while other out of range fields carry over
+    /// into the next unit
+    pub fn from_dos_datetime(
+        date: u16,
+        time: u16,
+    ) -> Self {
+        let year = DOS_YEAR_BASE + i64::from(date >> 9);
+        let month = u32::from(date >> 5 & 0x0f).clamp(1, 12);
+        let day = i64::from(date & 0x1f);
+        let secs = i64::from(time >> 11) * 3600
+            + i64::from(time >> 5 & 0x3f) * 60
+            + i64::from(time & 0x1f) * 2;
+        let days = days_from_civil(year, month, 1) + day - 1;
+        Seconds((days * SECS_PER_DAY + secs) as f64)
+    }
+
+    /// convert to an MS-DOS packed `(date, time)` pair in UTC, as stored in ZIP
+    /// archive entries
+    ///
+    /// DOS timestamps only have a 2-second resolution, so odd seconds and any
+    /// fraction are truncated. Times before 1980-01-01 or after 2107-12-31T23:59:58
+    /// are clamped to the representable range, as are non-finite seconds
+    #[must_use]
+    pub fn to_dos_datetime(&self) -> (u16, u16) {
+        let dt = match DateTime::from_seconds(*self) {
+            Some(dt) if dt.year < DOS_YEAR_BASE => return (0x21, 0),
+            Some(dt) if dt.year <= DOS_MAX_YEAR => dt,
+            None if self.0.is_nan() || self.0 < 0.0 => return (0x21, 0),
+            _ => return (0xff9f, 0xbf7d),
+        };
+        let date = ((dt.year - DOS_YEAR_BASE) as u16) << 9 | (dt.month as u16) << 5 | dt.day as u16;
+        let time = (dt.hour as u16) << 11 | (dt.minute as u16) << 5 | (dt.second / 2) as u16;
+        (date, time)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{civil_from_days, days_from_civil, FormatError};
+    use crate::Seconds;
+
+    #[test]
+    fn civil_round_trips() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(days_from_civil(2018, 12, 18), 17_883);
+        assert_eq!(days_from_civil(1969, 12, 31), -1);
+        for days in &[-719_468, -1, 0, 17_883, 2_932_896] {
+            let (y, m, d) = civil_from_days(*days);
+            assert_eq!(days_from_civil(y, m, d), *days);
+        }
+    }
+
+    #[test]
+    fn format_with_patterns() {
+        let secs = Seconds(1_545_136_342.711_932);
+        assert_eq!(
+            secs.format_with("%Y-%m-%d %H:%M:%S")
+                .expect("failed to format"),
+            "2018-12-18 12:32:22"
+        );
+        assert_eq!(
+            secs.format_with("%a, %d %b %Y %T.%f %z")
+                .expect("failed to format"),
+            "Tue, 18 Dec 2018 12:32:22.711932 +0000"
+        );
+        assert_eq!(
+            secs.format_with("%j %s 100%%").expect("failed to format"),
+            "352 1545136342 100%"
+        );
+        assert_eq!(
+            Seconds(-1.0)
+                .format_with("%F %T")
+                .expect("failed to format"),
+            "1969-12-31 23:59:59"
+        );
+    }
+
+    #[test]
+    fn format_with_invalid_patterns() {
+        let secs = Seconds(1_545_136_342.711_932);
+        assert_eq!(
+            secs.format_with("%Q"),
+            Err(FormatError::UnknownSpecifier('Q'))
+        );
+        assert_eq!(secs.format_with("%Y%"), Err(FormatError::Incomplete));
+        assert_eq!(
+            Seconds(f64::NAN).format_with("%Y"),
+            Err(FormatError::NonFinite)
+        );
+        assert_eq!(
+            Seconds(1e19).format_with("%Y"),
+            Err(FormatError::OutOfRange)
+        );
+    }
+
+    #[test]
+    fn weekday_and_day_of_year() {
+        let secs = Seconds(1_545_136_342.711_932);
+        assert_eq!(secs.weekday_utc(), 2);
+        assert_eq!(secs.day_of_year_utc(), 352);
+        assert_eq!(Seconds::EPOCH.weekday_utc(), 4);
+        assert_eq!(Seconds::EPOCH.day_of_year_utc(), 1);
+        // 1969-12-31 was a Wednesday and the last day of the year
+        assert_eq!(Seconds(-0.5).weekday_utc(), 3);
+        assert_eq!(Seconds(-0.5).day_of_year_utc(), 365);
+        // 2020-12-31 was the 366th day of a leap year
+        assert_eq!(Seconds(1_609_372_800.0).day_of_year_utc(), 366);
+        assert_eq!(Seconds(f64::INFINITY).weekday_utc(), 4);
+        assert_eq!(Seconds(f64::NAN).day_of_year_utc(), 1);
+    }
+
+    #[test]
+    fn iso_week_utc() {
+        // 2018-12-18
+        assert_eq!(Seconds(1_545_136_342.0).iso_week_utc(), (2018, 51));
+        // 2018-12-31 was a Monday in week 1 of 2019
+        assert_eq!(Seconds(1_546_214_400.0).iso_week_utc(), (2019, 1));
+        // 2021-01-03 was a Sunday in week 53 of 2020
+        assert_eq!(Seconds(1_609_632_000.0).iso_week_utc(), (2020, 53));
+        // 2021-01-04 was the Monday starting week 1 of 2021
+        assert_eq!(Seconds(1_609_718_400.0).iso_week_utc(), (2021, 1));
+        // 1970-01-01 was a Thursday in week 1
+        assert_eq!(Seconds::EPOCH.iso_week_utc(), (1970, 1));
+        // 1969-12-29 was the Monday starting week 1 of 1970
+        assert_eq!(Seconds(-3.0 * 86_400.0).iso_week_utc(), (1970, 1));
+        assert_eq!(Seconds(-3.5 * 86_400.0).iso_week_utc(), (1969, 52));
+        assert_eq!(Seconds(1e20).iso_week_utc().0, i32::MAX);
+        assert_eq!(Seconds(-1e20).iso_week_utc().0, i32::MIN);
+        assert_eq!(Seconds(f64::MAX).iso_week_utc().0, i32::MAX);
+    }
+
+    #[test]
+    fn from_rfc3339() {
+        assert_eq!(
+            Seconds::from_rfc3339("2018-12-18T12:32:22.711932Z"),
+            Ok(Seconds(1_545_136_342.711_932))
+        );
+        assert_eq!(
+            Seconds::from_rfc3339("2018-12-18T14:32:22+02:00"),
+            Ok(Seconds(1_545_136_342.0))
+        );
+        assert_eq!(
+            Seconds::from_rfc3339("2018-12-18t07:02:22-05:30"),
+            Ok(Seconds(1_545_136_342.0))
+        );
+        assert_eq!(
+            Seconds::from_rfc3339("1969-12-31T23:59:59Z"),
+            Ok(Seconds(-1.0))
+        );
+        for invalid in &[
+            "2018-12-18",
+            "2018-12-18T12:32:22",
+            "2018-02-30T12:32:22Z",
+            "2018-12-18T12:32:22.Z",
+            "2018-12-18T12:32:22+0200",
+            "2018-12-18T24:00:00Z",
+        ] {
+            assert!(Seconds::from_rfc3339(invalid).is_err(), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn parse_unix_or_rfc3339() {
+        assert_eq!(
+            Seconds::parse_unix_or_rfc3339("1545136342.711"),
+            Ok(Seconds(1_545_136_342.711))
+        );
+        assert_eq!(
+            Seconds::parse_unix_or_rfc3339("2018-12-18T12:32:22Z"),
+            Ok(Seconds(1_545_136_342.0))
+        );
+        let err = Seconds::parse_unix_or_rfc3339("yesterday").expect_err("expected an error");
+        assert_eq!(
+            err.to_string(),
+            "invalid unix seconds or rfc 3339 timestamp: \"yesterday\""
+        );
+    }
+
+    #[test]
+    fn to_rfc3339() {
+        assert_eq!(
+            Seconds(1_545_136_342.711_932).to_rfc3339(),
+            "2018-12-18T12:32:22.711932Z"
+        );
+        assert_eq!(Seconds(-1.0).to_rfc3339(), "1969-12-31T23:59:59.000000Z");
+        assert_eq!(Seconds(1e14).to_rfc3339(), "3170843-11-07T09:46:40.000000Z");
+        assert_eq!(
+            Seconds(-1e14).to_rfc3339(),
+            "-3166904-02-24T14:13:20.000000Z"
+        );
+        assert_eq!(Seconds(1e19).to_rfc3339(), Seconds(1e19).to_string());
+    }
+
+    #[test]
+    fn to_rfc3339_with_offset() {
+        let secs = Seconds(1_545_136_342.711_932);
+        assert_eq!(
+            secs.to_rfc3339_with_offset(2 * 3600),
+            "2018-12-18T14:32:22.711932+02:00"
+        );
+        assert_eq!(
+            secs.to_rfc3339_with_offset(-(13 * 3600 + 1800)),
+            "2018-12-17T23:02:22.711932-13:30"
+        );
+        assert_eq!(
+            secs.to_rfc3339_with_offset(0),
+            "2018-12-18T12:32:22.711932+00:00"
+        );
+        assert_eq!(
+            Seconds::from_rfc3339(&secs.to_rfc3339_with_offset(-5 * 3600)),
+            Ok(Seconds(1_545_136_342.711_932))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "utc offset 54000s is outside of +/-14 hours")]
+    fn to_rfc3339_with_invalid_offset() {
+        let _ = Seconds(1_545_136_342.711_932).to_rfc3339_with_offset(15 * 3600);
+    }
+
+    #[test]
+    #[should_panic(expected = "utc offset -2147483648s is outside of +/-14 hours")]
+    fn to_rfc3339_with_min_offset() {
+        let _ = Seconds(1_545_136_342.711_932).to_rfc3339_with_offset(i32::MIN);
+    }
+
+    #[test]
+    fn dos_datetime() {
+        // 2018-12-18T12:32:22Z
+        assert_eq!(
+            Seconds::from_dos_datetime(0x4d92, 0x640b),
+            Seconds(1_545_136_342.0)
+        );
+        assert_eq!(Seconds(1_545_136_342.0).to_dos_datetime(), (0x4d92, 0x640b));
+        // odd seconds and fractions are lost to the 2-second resolution
+        assert_eq!(Seconds(1_545_136_343.7).to_dos_datetime(), (0x4d92, 0x640b));
+        // 1980-01-01T00:00:00Z
+        assert_eq!(Seconds::from_dos_datetime(0x21, 0), Seconds(315_532_800.0));
+    }
+
+    #[test]
+    fn dos_datetime_clamps() {
+        assert_eq!(Seconds::EPOCH.to_dos_datetime(), (0x21, 0));
+        assert_eq!(Seconds(f64::NAN).to_dos_datetime(), (0x21, 0));
+        assert_eq!(Seconds(f64::INFINITY).to_dos_datetime(), (0xff9f, 0xbf7d));
+        assert_eq!(Seconds(1e12).to_dos_datetime(), (0xff9f, 0xbf7d));
+        assert_eq!(
+            Seconds::from_dos_datetime(0xff9f, 0xbf7d).to_dos_datetime(),
+            (0xff9f, 0xbf7d)
+        );
+    }
+
+    #[test]
+    fn to_rfc2822() {
+        assert_eq!(
+            Seconds(1_545_136_342.711_932).to_rfc2822(),
+            "Tue, 18 Dec 2018 12:32:22 +0000"
+        );
+        assert_eq!(
+            Seconds::EPOCH.to_rfc2822(),
+            "Thu, 01 Jan 1970 00:00:00 +0000"
+        );
+        assert_eq!(Seconds(f64::NAN).to_rfc2822(), "NaN");
+    }
+
+    #[test]
+    fn from_http_date() {
+        let expected = Ok(Seconds(784_111_777.0));
+        assert_eq!(
+            Seconds::from_http_date("Sun, 06 Nov 1994 08:49:37 GMT"),
+            expected
+        );
+        assert_eq!(
+            Seconds::from_http_date("Sunday, 06-Nov-94 08:49:37 GMT"),
+            expected
+        );
+        assert_eq!(
+            Seconds::from_http_date("Sun Nov  6 08:49:37 1994"),
+            expected
+        );
+        assert_eq!(
+            Seconds::from_http_date("Tuesday, 18-Dec-18 12:32:22 GMT"),
+            Ok(Seconds(1_545_136_342.0))
+        );
+        let imf_fixdate = Seconds(1_545_136_342.711_932)
+            .to_rfc2822()
+            .replace("+0000", "GMT");
+        assert_eq!(
+            Seconds::from_http_date(&imf_fixdate),
+            Ok(Seconds(1_545_136_342.0))
+        );
+    }
+
+    #[test]
+    fn from_http_date_invalid() {
+        for invalid in &[
+            "",
+            "Sun, 06 Nov 1994 08:49:37 PST",
+            "Sun, 6 Nov 1994 08:49:37 GMT",
+            "Sun, 06 Nov 94 08:49:37 GMT",
+            "Sun, 31 Nov 1994 08:49:37 GMT",
+            "Sun, 06 Foo 1994 08:49:37 GMT",
+            "Sun, 06 Nov 1994 24:49:37 GMT",
+            "Sun 06 Nov 1994 08:49:37 GMT",
+            "Sun, 06-Nov-94 08:49:37 GMT",
+            "Sunday, 06-Nov-1994 08:49:37 GMT",
+            "Sun Nov  6 08:49 1994",
+        ] {
+            assert_eq!(
+                Seconds::from_http_date(invalid).unwrap_err().to_string(),
+                format!("invalid http date: {:?}", invalid)
+            );
+        }
+    }
+
+    #[test]
+    fn parse_relative() {
+        let now = Seconds(1_545_136_342.5);
+        assert_eq!(Seconds::parse_relative("now", now), Ok(now));
+        assert_eq!(
+            Seconds::parse_relative("now-1h", now),
+            Ok(Seconds(1_545_132_742.5))
+        );
+        assert_eq!(
+            Seconds::parse_relative("now+30m", now),
+            Ok(Seconds(1_545_138_142.5))
+        );
+        assert_eq!(
+            Seconds::parse_relative("now-1.5d", now),
+            Ok(Seconds(1_545_006_742.5))
+        );
+        assert_eq!(
+            Seconds::parse_relative("now+250ms", now),
+            Ok(Seconds(1_545_136_342.75))
+        );
+        assert_eq!(
+            Seconds::parse_relative("1545136342", now),
+            Ok(Seconds(1_545_136_342.0))
+        );
+        assert_eq!(
+            Seconds::parse_relative("2018-12-18T12:32:22Z", now),
+            Ok(Seconds(1_545_136_342.0))
+        );
+    }
+
+    #[test]
+    fn parse_relative_invalid() {
+        let now = Seconds(1_545_136_342.5);
+        for invalid in &[
+            "",
+            "now-",
+            "now-1",
+            "now-1y",
+            "now--1h",
+            "now 1h",
+            "now-1e308w",
+            "now+1e308w",
+            "yesterday",
+        ] {
+            assert_eq!(
+                Seconds::parse_relative(invalid, now)
+                    .unwrap_err()
+                    .to_string(),
+                format!("invalid relative time: {:?}", invalid)
+            );
+        }
+    }
+
+    #[test]
+    fn to_rfc3339_nanos() {
+        // the nearest f64 to 1545136342.711932 is a little under it
+        assert_eq!(
+            Seconds(1_545_136_342.711_932).to_rfc3339_nanos(),
+            "2018-12-18T12:32:22.711931944Z"
+        );
+        assert_eq!(
+            Seconds(1.000_000_001).to_rfc3339_nanos(),
+            "1970-01-01T00:00:01.000000001Z"
+        );
+        assert_eq!(
+            Seconds(1_545_136_342.5).to_rfc3339_nanos(),
+            "2018-12-18T12:32:22.5Z"
+        );
+        assert_eq!(
+            Seconds(1_545_136_342.0).to_rfc3339_nanos(),
+            "2018-12-18T12:32:22Z"
+        );
+        assert_eq!(Seconds(-0.25).to_rfc3339_nanos(), "1969-12-31T23:59:59.75Z");
+        assert_eq!(Seconds(f64::NAN).to_rfc3339_nanos(), "NaN");
+        assert_eq!(Seconds(1e19).to_rfc3339_nanos(), Seconds(1e19).to_string());
+    }
+
+    #[test]
+    fn start_of_month_and_year_utc() {
+        let secs = Seconds(1_545_136_342.711_932);
+        // 2018-12-01T00:00:00Z and 2018-01-01T00:00:00Z
+        assert_eq!(secs.start_of_month_utc(), Seconds(1_543_622_400.0));
+        assert_eq!(secs.start_of_year_utc(), Seconds(1_514_764_800.0));
+        assert_eq!(
+            Seconds(1_543_622_400.0).start_of_month_utc(),
+            Seconds(1_543_622_400.0)
+        );
+        // 1969-12-15 snaps back to 1969-12-01 and 1969-01-01
+        let pre_epoch = Seconds(-17.0 * 86_400.0 + 0.5);
+        assert_eq!(pre_epoch.start_of_month_utc(), Seconds(-31.0 * 86_400.0));
+        assert_eq!(pre_epoch.start_of_year_utc(), Seconds(-365.0 * 86_400.0));
+        assert!(Seconds(f64::NAN).start_of_month_utc().is_nan());
+        // 3170843-11-01T00:00:00Z and 3170843-01-01T00:00:00Z
+        assert_eq!(
+            Seconds(1e14).start_of_month_utc(),
+            Seconds(99_999_999_446_400.0)
+        );
+        assert_eq!(
+            Seconds(1e14).start_of_year_utc(),
+            Seconds(99_999_973_180_800.0)
+        );
+        assert_eq!(Seconds(1e20).start_of_month_utc(), Seconds(1e20));
+        assert_eq!(Seconds(1e20).start_of_year_utc(), Seconds(1e20));
+    }
+
+    #[test]
+    fn add_months_utc() {
+        // 2019-01-31T12:32:22.5Z
+        let jan_31 = Seconds(1_548_937_942.5);
+        assert_eq!(
+            jan_31.add_months_utc(1).to_rfc3339(),
+            "2019-02-28T12:32:22.500000Z"
+        );
+        // 2020 was a leap year
+        assert_eq!(
+            Seconds(1_548_937_942.5 + 365.0 * 86_400.0)
+                .add_months_utc(1)
+                .to_rfc3339(),
+            "2020-02-29T12:32:22.500000Z"
+        );
+        assert_eq!(
+            jan_31.add_months_utc(-2).to_rfc3339(),
+            "2018-11-30T12:32:22.500000Z"
+        );
+        assert_eq!(
+            jan_31.add_months_utc(-13).to_rfc3339(),
+            "2017-12-31T12:32:22.500000Z"
+        );
+        assert_eq!(
+            jan_31.add_months_utc(24).to_rfc3339(),
+            "2021-01-31T12:32:22.500000Z"
+        );
+        assert_eq!(jan_31.add_months_utc(0), jan_31);
+        assert!(Seconds(f64::NAN).add_months_utc(1).is_nan());
+        assert_eq!(
+            Seconds(1e14).add_months_utc(1).to_rfc3339(),
+            "3170843-12-07T09:46:40.000000Z"
+        );
+        assert_eq!(Seconds(1e20).add_months_utc(1), Seconds(1e20));
+        assert!(Seconds(9.2e18).add_months_utc(i32::MAX).is_finite());
+    }
+
+    #[test]
+    fn business_days_between() {
+        // Friday 2018-12-14 through Tuesday 2018-12-18 spans a weekend
+        let friday = Seconds(1_544_788_800.0);
+        let tuesday = Seconds(1_545_136_342.0);
+        assert_eq!(friday.business_days_between(tuesday, |_| false), Some(2));
+        assert_eq!(tuesday.business_days_between(friday, |_| false), Some(-2));
+        assert_eq!(friday.business_days_between(friday, |_| false), Some(0));
+        // a holiday on Monday 2018-12-17
+        let monday = Seconds(1_545_048_000.0).epoch_day();
+        assert_eq!(
+            friday.business_days_between(tuesday, |day| day == monday),
+            Some(1)
+        );
+        assert_eq!(
+            friday.business_days_between(Seconds(f64::NAN), |_| false),
+            None
+        );
+    }
+
+    #[test]
+    fn business_days_between_whole_month() {
+        // December 2018 has 21 weekdays, with Christmas Eve and Christmas Day falling
+        // on Monday the 24th and Tuesday the 25th
+        let december = Seconds(1_543_622_400.0);
+        let january = Seconds(1_546_300_800.0);
+        let (christmas_eve, christmas) = (17_889, 17_890);
+        let is_holiday = |day| day == christmas_eve || day == christmas;
+        assert_eq!(december.business_days_between(january, |_| false), Some(21));
+        assert_eq!(december.business_days_between(january, is_holiday), Some(19));
+        assert_eq!(
+            january.business_days_between(december, |day| day == christmas),
+            Some(-20)
+        );
+        // Saturday the 1st is already skipped as a weekend
+        let saturday = december.epoch_day();
+        assert_eq!(
+            december.business_days_between(january, |day| day == saturday),
+            Some(21)
+        );
+    }
+
+    #[test]
+    fn business_days_between_long_spans() {
+        // 1,000,000 days spans 142,857 whole weeks and a leftover Thursday
+        let longest = Seconds(1_000_000.0 * 86_400.0);
+        assert_eq!(
+            Seconds::EPOCH.business_days_between(longest, |_| false),
+            Some(714_286)
+        );
+        assert_eq!(
+            Seconds::EPOCH.business_days_between(longest, |_| true),
+            Some(0)
+        );
+        assert_eq!(
+            Seconds::EPOCH.business_days_between(Seconds(1_000_001.0 * 86_400.0), |_| false),
+            None
+        );
+        assert_eq!(
+            Seconds::EPOCH.business_days_between(Seconds(1e15), |_| false),
+            None
+        );
+        assert_eq!(
+            Seconds(-f64::MAX).business_days_between(Seconds(f64::MAX), |_| false),
+            None
+        );
+    }
+}
@@ -0,0 +1,97 @@
+//! Deadlines expressed in unix seconds
+use crate::{Clock, Seconds, SystemClock};
+use std::time::Duration;
+
+/// A point in time by which something should happen
+///
+/// Methods which consult the current time have `_with` variants that read it from
+/// a [`Clock`](trait.Clock.html) instead of the system clock
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct Deadline(pub Seconds);
+
+impl Deadline {
+    /// create a deadline `dur` from now
+    pub fn from_now(dur: Duration) -> Self {
+        Self::from_now_with(&SystemClock, dur)
+    }
+
+    /// create a deadline `dur` from the clock's current time
+    pub fn from_now_with<C>(
+        clock: &C,
+        dur: Duration,
+    ) -> Self
+    where
+        C: Clock + ?Sized,
+    {
+        Deadline(clock.now() + dur)
+    }
+
+    /// return true once the current time has reached the deadline
+    #[must_use]
+    pub fn is_expired(&self) -> bool {
+        self.is_expired_with(&SystemClock)
+    }
+
+    /// return true once the clock's current time has reached the deadline
+    #[must_use]
+    pub fn is_expired_with<C>(
+        &self,
+        clock: &C,
+    ) -> bool
+    where
+        C: Clock + ?Sized,
+    {
+        clock.now().0 >= (self.0).0
+    }
+
+    /// return the time left until the deadline, or zero once it has expired
+    #[must_use]
+    pub fn remaining(&self) -> Duration {
+        self.remaining_with(&SystemClock)
+    }
+
+    /// return the time left until the deadline according to the clock, or zero
+    /// once it has expired
+    #[must_use]
+    pub fn remaining_with<C>(
+        &self,
+        clock: &C,
+    ) -> Duration
+    where
+        C: Clock + ?Sized,
+    {
+        let remaining = ((self.0).0 - clock.now().0).max(0.0);
+        Duration::try_from_secs_f64(remaining).unwrap_or(Duration::MAX)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Deadline;
+    use crate::{FixedClock, Seconds};
+    use std::time::Duration;
+
+    #[test]
+    fn future_deadline() {
+        let clock = FixedClock::new(Seconds(100.0));
+        let deadline = Deadline::from_now_with(&clock, Duration::from_secs(30));
+        assert_eq!(deadline, Deadline(Seconds(130.0)));
+        assert!(!deadline.is_expired_with(&clock));
+        assert_eq!(deadline.remaining_with(&clock), Duration::from_secs(30));
+    }
+
+    #[test]
+    fn expired_deadline() {
+        let clock = FixedClock::new(Seconds(100.0));
+        let deadline = Deadline(Seconds(90.0));
+        assert!(deadline.is_expired_with(&clock));
+        assert_eq!(deadline.remaining_with(&clock), Duration::from_secs(0));
+    }
+
+    #[test]
+    fn system_clock_deadline() {
+        let deadline = Deadline::from_now(Duration::from_secs(60));
+        assert!(!deadline.is_expired());
+        assert!(deadline.remaining() <= Duration::from_secs(60));
+    }
+}
@@ -0,0 +1,89 @@
+//! Maps `Seconds` to diesel's `Double` SQL type, such as PostgreSQL's
+//! `double precision`, for use directly in diesel models
+//!
+//! Nullable columns map to `Option<Seconds>` as usual
+use crate::Seconds;
+use diesel::{
+    backend::Backend,
+    deserialize::{self, FromSql},
+    serialize::{self, Output, ToSql},
+    sql_types::Double,
+};
+
+impl<DB> ToSql<Double, DB> for Seconds
+where
+    DB: Backend,
+    f64: ToSql<Double, DB>,
+{
+    fn to_sql<'b>(
+        &'b self,
+        out: &mut Output<'b, '_, DB>,
+    ) -> serialize::Result {
+        self.0.to_sql(out)
+    }
+}
+
+impl<DB> FromSql<Double, DB> for Seconds
+where
+    DB: Backend,
+    f64: FromSql<Double, DB>,
+{
+    fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+        f64::from_sql(bytes).map(Seconds)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Seconds;
+    use diesel::{prelude::*, sqlite::SqliteConnection};
+
+    diesel::table! {
+        events (id) {
+            id -> Integer,
+            at -> Double,
+            finished_at -> Nullable<Double>,
+        }
+    }
+
+    #[derive(Debug, PartialEq, Queryable)]
+    struct Event {
+        id: i32,
+        at: Seconds,
+        finished_at: Option<Seconds>,
+    }
+
+    #[derive(Insertable)]
+    #[diesel(table_name = events)]
+    struct NewEvent {
+        at: Seconds,
+        finished_at: Option<Seconds>,
+    }
+
+    #[test]
+    fn round_trips_through_sqlite() -> Result<(), Box<dyn std::error::Error>> {
+        let mut conn = SqliteConnection::establish(":memory:")?;
+        diesel::sql_query(
+            "CREATE TABLE events (id INTEGER PRIMARY KEY, at REAL NOT NULL, finished_at REAL)",
+        )
+        .execute(&mut conn)?;
+        diesel::insert_into(events::table)
+            .values(&NewEvent {
+                at: Seconds(1_545_136_342.711_932),
+                finished_at: None,
+            })
+            .execute(&mut conn)?;
+        let event = events::table
+            .filter(events::at.eq(Seconds(1_545_136_342.711_932)))
+            .first::<Event>(&mut conn)?;
+        assert_eq!(
+            event,
+            Event {
+                id: 1,
+                at: Seconds(1_545_136_342.711_932),
+                finished_at: None,
+            }
+        );
+        Ok(())
+    }
+}
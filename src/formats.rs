@@ -0,0 +1,394 @@
+//! Conversions to and from timestamp formats anchored at other epochs
+use crate::Seconds;
+
+/// seconds from the NTP epoch, 1900-01-01, to the unix epoch
+const NTP_UNIX_OFFSET: f64 = 2_208_988_800.0;
+
+/// the NTP fractional second resolution, 2^32 units per second
+const NTP_FRACTION: f64 = 4_294_967_296.0;
+
+/// seconds from the Windows FILETIME epoch, 1601-01-01, to the unix epoch
+const FILETIME_UNIX_OFFSET: i64 = 11_644_473_600;
+
+/// FILETIME intervals, of 100 nanoseconds, per second
+const FILETIME_PER_SEC: u64 = 10_000_000;
+
+/// days from the Excel serial date epoch, 1899-12-30, to the unix epoch
+const EXCEL_UNIX_OFFSET_DAYS: f64 = 25_569.0;
+
+/// the first serial date unaffected by Excel treating 1900 as a leap year
+const EXCEL_MARCH_1900: f64 = 61.0;
+
+/// days from the 1904 date system's epoch, 1904-01-01, to the unix epoch
+const MAC_1904_UNIX_OFFSET_DAYS: f64 = 24_107.0;
+
+/// Spreadsheet date systems, each counting fractional days from a different epoch.
+/// See [`Seconds::from_serial`](struct.Seconds.html#method.from_serial)
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum SerialDateSystem {
+    /// the default 1900 date system used by Excel on Windows, Google Sheets and
+    /// LibreOffice, counting days since 1899-12-30 with Excel's 1900 leap year bug
+    Excel1900,
+    /// the 1904 date system used by older Excel for Mac files, counting days since
+    /// 1904-01-01
+    Mac1904,
+}
+
+impl Seconds {
+    /// create seconds from a 64 bit NTP timestamp
+    ///
+    /// NTP timestamps are 32.32 fixed point seconds since 1900-01-01, the upper 32
+    /// bits holding whole seconds and the lower 32 the fraction of a second
+    pub fn from_ntp_timestamp(ntp: u64) -> Self {
+        let whole = (ntp >> 32) as f64;
+        let fraction = (ntp & 0xffff_ffff) as f64 / NTP_FRACTION;
+        Seconds(whole - NTP_UNIX_OFFSET + fraction)
+    }
+
+    /// convert to a 64 bit NTP timestamp
+    ///
+    /// Only NTP era 0, from 1900 until early 2036, is representable. Timestamps
+    /// outside of it wrap around, as they do on the wire
+    #[must_use]
+    pub fn to_ntp_timestamp(&self) -> u64 {
+        let ntp = self.0 + NTP_UNIX_OFFSET;
+        let mut whole = ntp.floor() as i64;
+        let mut fraction = ((ntp - ntp.floor()) * NTP_FRACTION).round() as u64;
+        // rounding the fraction may carry into the next whole second
+        if fraction > 0xffff_ffff {
+            whole += 1;
+            fraction = 0;
+        }
+        ((whole as u64 & 0xffff_ffff) << 32) | fraction
+    }
+
+    /// create seconds from a Windows FILETIME, a count of 100 nanosecond intervals
+    /// since 1601-01-01
+    pub fn from_windows_filetime(ft: u64) -> Self {
+        // whole seconds and the remainder are converted separately to avoid losing
+        // precision on large values
+        let whole = (ft / FILETIME_PER_SEC) as i64 - FILETIME_UNIX_OFFSET;
+        let fraction = (ft % FILETIME_PER_SEC) as f64 / FILETIME_PER_SEC as f64;
+        Seconds(whole as f64 + fraction)
+    }
+
+    /// convert to a Windows FILETIME, a count of 100 nanosecond intervals since
+    /// 1601-01-01
+    ///
+    /// Timestamps before 1601 saturate at zero and those too far in the future
+    /// saturate at `u64::MAX`
+    #[must_use]
+    pub fn to_windows_filetime(&self) -> u64 {
+        let (secs, nanos) = self.to_timestamp_parts();
+        // widened before offsetting, as seconds beyond the range of an i64 saturate
+        let intervals = (i128::from(secs) + i128::from(FILETIME_UNIX_OFFSET))
+            * i128::from(FILETIME_PER_SEC)
+            + i128::from((nanos + 50) / 100);
+        intervals.max(0).min(i128::from(u64::MAX)) as u64
+    }
+
+    /// create seconds from a Prometheus sample timestamp, as exposed in the text
+    /// exposition format and returned by the HTTP query API
+    ///
+    /// Both already use floating point seconds since the epoch, so this is a pass
+    /// through
+    pub fn from_prometheus_seconds(f: f64) -> Self {
+        Seconds(f)
+    }
+
+    /// convert to a Prometheus remote write sample timestamp, integer milliseconds
+    /// since the epoch
+    ///
+    /// Seconds are rounded to the nearest millisecond, matching how Prometheus
+    /// converts float seconds itself. `NaN` converts to `0` and values beyond the
+    /// range of an `i64` saturate
+    #[must_use]
+    pub fn to_prometheus_millis(&self) -> i64 {
+        // float to integer casts saturate
+        (self.0 * 1.0e3).round() as i64
+    }
+
+    /// create seconds from an Excel serial date, a fractional count of days since
+    /// 1899-12-30 in Excel's default 1900 date system
+    ///
+    /// Excel incorrectly treats 1900 as a leap year, so serial dates before
+    /// 1900-03-01 are shifted forward a day to correct for it. The fictitious
+    /// 1900-02-29, serial 60, becomes 1900-02-28
+    pub fn from_excel_serial(serial: f64) -> Self {
+        let days = if serial < EXCEL_MARCH_1900 - 1.0 {
+            serial + 1.0
+        } else {
+            serial
+        };
+        Seconds((days - EXCEL_UNIX_OFFSET_DAYS) * 86_400.0)
+    }
+
+    /// convert to an Excel serial date, a fractional count of days since 1899-12-30
+    /// in Excel's default 1900 date system
+    ///
+    /// Dates before 1900-03-01 are shifted back a day to match Excel's treatment
+    /// of 1900 as a leap year
+    #[must_use]
+    pub fn to_excel_serial(&self) -> f64 {
+        let days = self.0 / 86_400.0 + EXCEL_UNIX_OFFSET_DAYS;
+        if days < EXCEL_MARCH_1900 {
+            days - 1.0
+        } else {
+            days
+        }
+    }
+
+    /// the UTC instants at which each leap second took effect, paired with the
+    /// resulting TAI - UTC offset in seconds, as published by the IERS through the
+    /// leap second of 2016-12-31
+    pub const LEAP_SECONDS: [(Seconds, f64); 28] = [
+        // 1972-01-01
+        (Seconds(63_072_000.0), 10.0),
+        // 1972-07-01
+        (Seconds(78_796_800.0), 11.0),
+        // 1973-01-01
+        (Seconds(94_694_400.0), 12.0),
+        // 1974-01-01
+        (Seconds(126_230_400.0), 13.0),
+        // 1975-01-01
+        (Seconds(157_766_400.0), 14.0),
+        // 1976-01-01
+        (Seconds(189_302_400.0), 15.0),
+        // 1977-01-01
+        (Seconds(220_924_800.0), 16.0),
+        // 1978-01-01
+        (Seconds(252_460_800.0), 17.0),
+        // 1979-01-01
+        (Seconds(283_996_800.0), 18.0),
+        // 1980-01-01
+        (Seconds(315_532_800.0), 19.0),
+        // 1981-07-01
+        (Seconds(362_793_600.0), 20.0),
+        // 1982-07-01
+        (Seconds(394_329_600.0), 21.0),
+        // 1983-07-01
+        (Seconds(425_865_600.0), 22.0),
+        // 1985-07-01
+        (Seconds(489_024_000.0), 23.0),
+        // 1988-01-01
+        (Seconds(567_993_600.0), 24.0),
+        // 1990-01-01
+        (Seconds(631_152_000.0), 25.0),
+        // 1991-01-01
+        (Seconds(662_688_000.0), 26.0),
+        // 1992-07-01
+        (Seconds(709_948_800.0), 27.0),
+        // 1993-07-01
+        (Seconds(741_484_800.0), 28.0),
+        // 1994-07-01
+        (Seconds(773_020_800.0), 29.0),
+        // 1996-01-01
+        (Seconds(820_454_400.0), 30.0),
+        // 1997-07-01
+        (Seconds(867_715_200.0), 31.0),
+        // 1999-01-01
+        (Seconds(915_148_800.0), 32.0),
+        // 2006-01-01
+        (Seconds(1_136_073_600.0), 33.0),
+        // 2009-01-01
+        (Seconds(1_230_768_000.0), 34.0),
+        // 2012-07-01
+        (Seconds(1_341_100_800.0), 35.0),
+        // 2015-07-01
+        (Seconds(1_435_708_800.0), 36.0),
+        // 2017-01-01
+        (Seconds(1_483_228_800.0), 37.0),
+    ];
+
+    /// return true, as `Seconds` counts POSIX time, which ignores leap seconds
+    ///
+    /// Every UTC day is exactly 86400 seconds long, as with `SystemTime`, so a leap
+    /// second repeats the timestamp of the second which follows it. See
+    /// [`Seconds::from_tai`](struct.Seconds.html#method.from_tai) for converting
+    /// from a continuous time scale
+    #[must_use]
+    pub const fn is_posix_seconds() -> bool {
+        true
+    }
+
+    /// convert TAI seconds since 1970-01-01 to POSIX seconds since the unix epoch
+    /// using a table of `(UTC instant, TAI - UTC offset)` leap second entries in
+    /// ascending order, such as [`Seconds::LEAP_SECONDS`](struct.Seconds.html#associatedconstant.LEAP_SECONDS)
+    ///
+    /// The TAI second inserted by a leap second has no POSIX representation and maps
+    /// onto the same POSIX second as the one after it. TAI seconds before the first
+    /// table entry are returned unchanged
+    pub fn from_tai(
+        tai_seconds: f64,
+        leap_table: &[(Seconds, f64)],
+    ) -> Self {
+        let offset = leap_table
+            .iter()
+            .rev()
+            .find(|(utc, offset)| tai_seconds >= utc.0 + offset)
+            .map(|&(_, offset)| offset)
+            .unwrap_or(0.0);
+        Seconds(tai_seconds - offset)
+    }
+
+    /// create seconds from a spreadsheet serial date in the given date system
+    ///
+    /// Only the 1900 date system has the 1900 leap year bug, see
+    /// [`Seconds::from_excel_serial`](struct.Seconds.html#method.from_excel_serial)
+    pub fn from_serial(
+        serial: f64,
+        system: SerialDateSystem,
+    ) -> Self {
+        match system {
+            SerialDateSystem::Excel1900 => Seconds::from_excel_serial(serial),
+            SerialDateSystem::Mac1904 => Seconds((serial - MAC_1904_UNIX_OFFSET_DAYS) * 86_400.0),
+        }
+    }
+
+    /// convert to a spreadsheet serial date in the given date system
+    #[must_use]
+    pub fn to_serial(
+        &self,
+        system: SerialDateSystem,
+    ) -> f64 {
+        match system {
+            SerialDateSystem::Excel1900 => self.to_excel_serial(),
+            SerialDateSystem::Mac1904 => self.0 / 86_400.0 + MAC_1904_UNIX_OFFSET_DAYS,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Seconds, SerialDateSystem};
+
+    #[test]
+    fn ntp_timestamp() {
+        let ntp = 0xdfc3_6756_8000_0000;
+        assert_eq!(Seconds::from_ntp_timestamp(ntp), Seconds(1_545_136_342.5));
+        assert_eq!(Seconds(1_545_136_342.5).to_ntp_timestamp(), ntp);
+        assert_eq!(Seconds::EPOCH.to_ntp_timestamp(), 2_208_988_800 << 32);
+        assert_eq!(
+            Seconds::from_ntp_timestamp(2_208_988_800 << 32),
+            Seconds::EPOCH
+        );
+    }
+
+    #[test]
+    fn windows_filetime() {
+        let ft = 131_896_099_425_000_000;
+        assert_eq!(Seconds::from_windows_filetime(ft), Seconds(1_545_136_342.5));
+        assert_eq!(Seconds(1_545_136_342.5).to_windows_filetime(), ft);
+        assert_eq!(
+            Seconds::EPOCH.to_windows_filetime(),
+            116_444_736_000_000_000
+        );
+        assert_eq!(Seconds(-11_644_473_601.0).to_windows_filetime(), 0);
+        assert_eq!(Seconds(1e20).to_windows_filetime(), u64::MAX);
+        assert_eq!(Seconds(f64::INFINITY).to_windows_filetime(), u64::MAX);
+        assert_eq!(Seconds(f64::NEG_INFINITY).to_windows_filetime(), 0);
+    }
+
+    #[test]
+    fn prometheus_timestamps() {
+        assert_eq!(
+            Seconds::from_prometheus_seconds(1_545_136_342.123),
+            Seconds(1_545_136_342.123)
+        );
+        assert_eq!(
+            Seconds(1_545_136_342.123).to_prometheus_millis(),
+            1_545_136_342_123
+        );
+        assert_eq!(
+            Seconds(1_545_136_342.5).to_prometheus_millis(),
+            1_545_136_342_500
+        );
+        assert_eq!(Seconds(-0.0016).to_prometheus_millis(), -2);
+        assert_eq!(Seconds(f64::INFINITY).to_prometheus_millis(), i64::MAX);
+    }
+
+    #[test]
+    fn excel_serial() {
+        // 2018-12-18T00:00:00Z
+        assert_eq!(
+            Seconds::from_excel_serial(43_452.0),
+            Seconds(1_545_091_200.0)
+        );
+        assert_eq!(Seconds(1_545_091_200.0).to_excel_serial(), 43_452.0);
+        assert_eq!(
+            Seconds::from_excel_serial(43_452.5),
+            Seconds(1_545_134_400.0)
+        );
+        let secs = Seconds(1_545_136_342.5);
+        assert!((Seconds::from_excel_serial(secs.to_excel_serial()).0 - secs.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn excel_serial_1900_leap_year_bug() {
+        // serial 1 is 1900-01-01 and serial 61 is 1900-03-01
+        assert_eq!(Seconds::from_excel_serial(1.0), Seconds(-2_208_988_800.0));
+        assert_eq!(Seconds(-2_208_988_800.0).to_excel_serial(), 1.0);
+        assert_eq!(Seconds::from_excel_serial(61.0), Seconds(-2_203_891_200.0));
+        assert_eq!(Seconds(-2_203_891_200.0).to_excel_serial(), 61.0);
+        // the fictitious 1900-02-29 falls back to 1900-02-28, serial 59
+        assert_eq!(
+            Seconds::from_excel_serial(60.0),
+            Seconds::from_excel_serial(59.0)
+        );
+    }
+
+    #[test]
+    fn posix_seconds() {
+        assert!(Seconds::is_posix_seconds());
+    }
+
+    #[test]
+    fn from_tai_across_leap_second() {
+        // the leap second inserted at the end of 2016-12-31 moved TAI - UTC from 36 to 37
+        let new_year = 1_483_228_800.0;
+        let table = &Seconds::LEAP_SECONDS;
+        assert_eq!(
+            Seconds::from_tai(new_year + 35.5, table),
+            Seconds(new_year - 0.5)
+        );
+        assert_eq!(
+            Seconds::from_tai(new_year + 36.5, table),
+            Seconds(new_year + 0.5)
+        );
+        assert_eq!(
+            Seconds::from_tai(new_year + 37.5, table),
+            Seconds(new_year + 0.5)
+        );
+        assert_eq!(
+            Seconds::from_tai(1_545_136_342.0 + 37.0, table),
+            Seconds(1_545_136_342.0)
+        );
+        assert_eq!(Seconds::from_tai(1_000.0, table), Seconds(1_000.0));
+        assert_eq!(Seconds::from_tai(1_000.0, &[]), Seconds(1_000.0));
+    }
+
+    #[test]
+    fn serial_date_systems() {
+        // 2018-12-18T12:00:00Z
+        let secs = Seconds(1_545_134_400.0);
+        assert_eq!(secs.to_serial(SerialDateSystem::Excel1900), 43_452.5);
+        assert_eq!(secs.to_serial(SerialDateSystem::Mac1904), 41_990.5);
+        assert_eq!(
+            Seconds::from_serial(43_452.5, SerialDateSystem::Excel1900),
+            secs
+        );
+        assert_eq!(
+            Seconds::from_serial(41_990.5, SerialDateSystem::Mac1904),
+            secs
+        );
+        // the 1904 epoch itself, with no leap year bug to correct
+        assert_eq!(
+            Seconds::from_serial(0.0, SerialDateSystem::Mac1904),
+            Seconds(-2_082_844_800.0)
+        );
+        assert_eq!(
+            Seconds(-2_208_988_800.0).to_serial(SerialDateSystem::Mac1904),
+            -1_460.0
+        );
+    }
+}
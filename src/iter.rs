@@ -0,0 +1,268 @@
+//! Iterators over ranges of time
+use crate::Seconds;
+#[cfg(feature = "date")]
+use std::convert::TryFrom;
+use std::time::Duration;
+
+/// An iterator of consecutive `[start, end)` windows tiling a range of time.
+/// See [`Seconds::windows`](struct.Seconds.html#method.windows)
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Windows {
+    from: f64,
+    to: f64,
+    width: f64,
+    index: u64,
+}
+
+impl Iterator for Windows {
+    type Item = (Seconds, Seconds);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.width <= 0.0 || !self.from.is_finite() || !self.to.is_finite() {
+            return None;
+        }
+        // offsets are computed from the range start, rather than accumulated,
+        // so that rounding errors don't compound over many windows
+        let start = self.from + self.index as f64 * self.width;
+        if start >= self.to {
+            return None;
+        }
+        self.index += 1;
+        let end = (self.from + self.index as f64 * self.width).min(self.to);
+        Some((Seconds(start), Seconds(end)))
+    }
+}
+
+/// An endless iterator of epoch aligned ticks.
+/// See [`Seconds::aligned_iter`](struct.Seconds.html#method.aligned_iter)
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct AlignedTicks {
+    first: f64,
+    period: f64,
+    index: u64,
+}
+
+impl Iterator for AlignedTicks {
+    type Item = Seconds;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.period <= 0.0 || !self.first.is_finite() {
+            return None;
+        }
+        let tick = (self.first + self.index as f64) * self.period;
+        self.index += 1;
+        Some(Seconds(tick))
+    }
+}
+
+/// An iterator of successive UTC midnights.
+/// See [`Seconds::daily_midnights_utc`](struct.Seconds.html#method.daily_midnights_utc)
+#[cfg(feature = "date")]
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct DailyMidnights {
+    day: i64,
+    end: i64,
+}
+
+#[cfg(feature = "date")]
+impl Iterator for DailyMidnights {
+    type Item = Seconds;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.day >= self.end {
+            return None;
+        }
+        // scaled in f64, as days near the ends of an i64 overflow as seconds
+        let midnight = Seconds(self.day as f64 * 86_400.0);
+        self.day += 1;
+        Some(midnight)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self
+            .end
+            .checked_sub(self.day)
+            .and_then(|remaining| usize::try_from(remaining.max(0)).ok())
+        {
+            Some(remaining) => (remaining, Some(remaining)),
+            None => (usize::MAX, None),
+        }
+    }
+}
+
+impl Seconds {
+    /// return an iterator of consecutive `[start, end)` windows, each `width` wide,
+    /// covering `[from, to)`
+    ///
+    /// When the range is not a multiple of `width` the final window is clipped so
+    /// that it ends at `to`. An empty range, a zero `width` or a non-finite `from` or
+    /// `to` yields no windows
+    pub fn windows(
+        from: Seconds,
+        to: Seconds,
+        width: Duration,
+    ) -> Windows {
+        Windows {
+            from: from.0,
+            to: to.0,
+            width: width.as_secs_f64(),
+            index: 0,
+        }
+    }
+
+    /// return an endless iterator of the multiples of `period` since the epoch, starting
+    /// with the first at or after `from`
+    ///
+    /// Unlike [`Seconds::windows`](struct.Seconds.html#method.windows), ticks are
+    /// aligned to the epoch rather than to `from`, so a 60 second period ticks on each
+    /// minute boundary. A zero `period` or non-finite `from` yields no ticks
+    pub fn aligned_iter(
+        from: Seconds,
+        period: Duration,
+    ) -> AlignedTicks {
+        let period = period.as_secs_f64();
+        AlignedTicks {
+            first: (from.0 / period).ceil(),
+            period,
+            index: 0,
+        }
+    }
+
+    /// return an iterator of the UTC midnights starting with the day containing
+    /// `from`, up to but excluding the day containing `to`
+    ///
+    /// UTC days are always 86400 seconds long, so each midnight is one fixed step
+    /// after the last. A `to` within the same day as `from`, before it, or either
+    /// being non-finite yields no midnights
+    #[cfg(feature = "date")]
+    pub fn daily_midnights_utc(
+        from: Seconds,
+        to: Seconds,
+    ) -> DailyMidnights {
+        if !from.is_finite() || !to.is_finite() {
+            return DailyMidnights { day: 0, end: 0 };
+        }
+        DailyMidnights {
+            day: from.epoch_day(),
+            end: to.epoch_day(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Seconds;
+    use std::time::Duration;
+
+    #[test]
+    fn windows_tile_range() {
+        let windows: Vec<_> =
+            Seconds::windows(Seconds(0.0), Seconds(10.0), Duration::from_secs(3)).collect();
+        assert_eq!(
+            windows,
+            vec![
+                (Seconds(0.0), Seconds(3.0)),
+                (Seconds(3.0), Seconds(6.0)),
+                (Seconds(6.0), Seconds(9.0)),
+                (Seconds(9.0), Seconds(10.0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn windows_empty() {
+        assert_eq!(
+            Seconds::windows(Seconds(10.0), Seconds(0.0), Duration::from_secs(3)).count(),
+            0
+        );
+        assert_eq!(
+            Seconds::windows(Seconds(0.0), Seconds(10.0), Duration::from_secs(0)).count(),
+            0
+        );
+        assert_eq!(
+            Seconds::windows(Seconds(f64::NAN), Seconds(10.0), Duration::from_secs(3)).count(),
+            0
+        );
+        assert_eq!(
+            Seconds::windows(Seconds(0.0), Seconds(f64::INFINITY), Duration::from_secs(3)).count(),
+            0
+        );
+    }
+
+    #[test]
+    fn aligned_iter_ticks_on_boundaries() {
+        let ticks: Vec<_> = Seconds::aligned_iter(Seconds(65.0), Duration::from_secs(60))
+            .take(3)
+            .collect();
+        assert_eq!(ticks, vec![Seconds(120.0), Seconds(180.0), Seconds(240.0)]);
+        let ticks: Vec<_> = Seconds::aligned_iter(Seconds(120.0), Duration::from_secs(60))
+            .take(2)
+            .collect();
+        assert_eq!(ticks, vec![Seconds(120.0), Seconds(180.0)]);
+    }
+
+    #[test]
+    fn aligned_iter_empty() {
+        assert_eq!(
+            Seconds::aligned_iter(Seconds(65.0), Duration::from_secs(0)).count(),
+            0
+        );
+        assert_eq!(
+            Seconds::aligned_iter(Seconds(f64::NAN), Duration::from_secs(60)).count(),
+            0
+        );
+    }
+
+    #[cfg(feature = "date")]
+    #[test]
+    fn daily_midnights_utc_span_days() {
+        // 2018-12-18T12:32:22Z through 2018-12-21T06:00:00Z
+        let midnights: Vec<_> =
+            Seconds::daily_midnights_utc(Seconds(1_545_136_342.0), Seconds(1_545_372_000.0))
+                .collect();
+        assert_eq!(
+            midnights,
+            vec![
+                Seconds(1_545_091_200.0),
+                Seconds(1_545_177_600.0),
+                Seconds(1_545_264_000.0),
+            ]
+        );
+    }
+
+    #[cfg(feature = "date")]
+    #[test]
+    fn daily_midnights_utc_empty() {
+        assert_eq!(
+            Seconds::daily_midnights_utc(Seconds(1_545_136_342.0), Seconds(1_545_170_000.0))
+                .count(),
+            0
+        );
+        assert_eq!(
+            Seconds::daily_midnights_utc(Seconds(1_545_372_000.0), Seconds(1_545_136_342.0))
+                .count(),
+            0
+        );
+        assert_eq!(
+            Seconds::daily_midnights_utc(Seconds(f64::NAN), Seconds(1_545_136_342.0)).count(),
+            0
+        );
+    }
+
+    #[cfg(feature = "date")]
+    #[test]
+    fn daily_midnights_utc_extreme_days() {
+        let mut midnights = Seconds::daily_midnights_utc(Seconds(1e19), Seconds(1e20));
+        assert_eq!(
+            midnights.next(),
+            Some(Seconds(115_740_740_740_740.0 * 86_400.0))
+        );
+        assert_eq!(
+            Seconds::daily_midnights_utc(Seconds(-f64::MAX), Seconds(f64::MAX)).size_hint(),
+            (usize::MAX, None)
+        );
+    }
+}
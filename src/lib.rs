@@ -17,11 +17,26 @@
 //! }
 //! ```
 //!
+//! # Structured logging
+//!
+//! `Seconds` implements `Display` as its bare numeric value, while `Debug` wraps it
+//! as `Seconds(..)`, or with `{:#?}` breaks it down into its value, bit pattern and
+//! finiteness. When recording seconds as a [tracing](https://docs.rs/tracing) field
+//! use the `%` sigil, which records with `Display`, so the field holds the plain
+//! number
+//!
+//! ```rust,ignore
+//! let started = unisecs::Seconds::now();
+//! // records started=1545136342.711932 rather than started=Seconds(1545136342.711932)
+//! tracing::info!(started = %started, "job started");
+//! ```
+//!
 //! # Features
 //!
 //! ## serde
 //!
-//! Adds ability to serialize and deserialize seconds with serde. This is
+//! Adds ability to serialize and deserialize seconds with serde. Non-finite
+//! seconds fail to serialize rather than producing invalid output. This is
 //! enabled by default. To turn if off add the following to your `Cargo.toml`
 //! file
 //!
@@ -30,13 +45,142 @@
 //!  version = "..."
 //!  default-features = false
 //! ```
+//!
+//! ## date
+//!
+//! Adds UTC calendar support, such as formatting seconds with strftime style
+//! patterns, without depending on a timezone database. Together with `serde` this
+//! also adds the `serde_rich` module and `SecondsFormat::Rfc3339`. This is not
+//! enabled by default
+//!
+//! ```toml
+//! [dependencies.unisecs]
+//!  version = "..."
+//!  features = ["date"]
+//! ```
+//!
+//! ## diesel
+//!
+//! Maps `Seconds` to [diesel](https://diesel.rs)'s `Double` SQL type, such as
+//! PostgreSQL's `double precision`, so seconds can be used directly as fields of
+//! `Queryable` and `Insertable` models. Nullable columns map to `Option<Seconds>`.
+//! This is not enabled by default
+//!
+//! ```toml
+//! [dependencies.unisecs]
+//!  version = "..."
+//!  features = ["diesel"]
+//! ```
+//!
+//! ## json
+//!
+//! Adds `Seconds::from_json_value` for extracting seconds from dynamically typed
+//! `serde_json::Value`s. This is not enabled by default
+//!
+//! ```toml
+//! [dependencies.unisecs]
+//!  version = "..."
+//!  features = ["json"]
+//! ```
+//!
+//! ## coarse-clock
+//!
+//! Makes `Seconds::now_coarse()` read Linux's `CLOCK_REALTIME_COARSE` clock through
+//! `libc`, trading precision for a cheaper clock read. On other platforms
+//! `Seconds::now_coarse()` falls back to `Seconds::now()`. This is not enabled
+//! by default
+//!
+//! ```toml
+//! [dependencies.unisecs]
+//!  version = "..."
+//!  features = ["coarse-clock"]
+//! ```
+//!
+//! ## rand
+//!
+//! Adds `Seconds::with_jitter` for adding a random offset drawn from a caller
+//! provided [rand](https://docs.rs/rand) generator, and makes `SecondsRange` a
+//! `rand` distribution of timestamps. This is not enabled by default
+//!
+//! ```toml
+//! [dependencies.unisecs]
+//!  version = "..."
+//!  features = ["rand"]
+//! ```
+//!
+//! ## sqlx
+//!
+//! Implements [sqlx](https://docs.rs/sqlx)'s `Type`, `Encode` and `Decode` for
+//! `Seconds` for every database with an `f64` mapping, binding seconds to
+//! PostgreSQL's `DOUBLE PRECISION`, MySQL's `DOUBLE` and SQLite's `REAL` columns.
+//! Nullable columns map to `Option<Seconds>`. This is not enabled by default
+//!
+//! ```toml
+//! [dependencies.unisecs]
+//!  version = "..."
+//!  features = ["sqlx"]
+//! ```
+//!
+//! ## test-clock
+//!
+//! Adds `Seconds::freeze` and `Seconds::unfreeze` for stopping the clock read by
+//! `Seconds::now()` in tests, without plumbing a `Clock` through every call site.
+//! This is not enabled by default and is intended only for dev-dependencies
+//!
+//! ```toml
+//! [dev-dependencies.unisecs]
+//!  version = "..."
+//!  features = ["test-clock"]
+//! ```
 #[cfg(feature = "serde")]
 use serde::{de, ser, Serializer};
 
+mod clock;
+#[cfg(feature = "date")]
+mod date;
+mod deadline;
+#[cfg(feature = "diesel")]
+mod diesel_types;
+mod formats;
+mod iter;
+#[cfg(feature = "serde")]
+pub mod serde_duration_struct;
+#[cfg(feature = "serde")]
+pub mod serde_flexible;
+#[cfg(feature = "serde")]
+pub mod serde_micros;
+#[cfg(feature = "serde")]
+pub mod serde_null_epoch;
+#[cfg(all(feature = "serde", feature = "date"))]
+pub mod serde_rich;
+#[cfg(feature = "serde")]
+pub mod serde_secs_nanos;
+#[cfg(feature = "serde")]
+pub mod serde_sentinel;
+#[cfg(feature = "sqlx")]
+mod sqlx_types;
+mod stream;
+
+pub use crate::clock::{Clock, FixedClock, OffsetClock, SystemClock};
+#[cfg(feature = "date")]
+pub use crate::date::FormatError;
+pub use crate::deadline::Deadline;
+pub use crate::formats::SerialDateSystem;
+#[cfg(feature = "date")]
+pub use crate::iter::DailyMidnights;
+pub use crate::iter::{AlignedTicks, Windows};
+pub use crate::stream::{Coalescer, DriftClamp, MonotonicClamp};
+
 use std::{
+    cmp::Ordering,
+    convert::TryFrom,
+    error::Error,
     fmt,
-    ops::{Add, Sub},
-    time::{Duration, SystemTime, UNIX_EPOCH},
+    hash::{Hash, Hasher},
+    ops::{Add, Rem, Sub},
+    str::FromStr,
+    sync::OnceLock,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
 };
 
 /// Represents fractional seconds since the [unix epoch](https://en.wikipedia.org/wiki/Unix_time)
@@ -47,9 +191,291 @@ use std::{
 /// the system time's `now` value
 ///
 /// You can also and and subtract durations from Seconds.
-#[derive(Debug, PartialEq, Copy, Clone)]
+///
+/// `Seconds` are `#[must_use]`. Because they are `Copy`, methods like `trunc` return
+/// a new value rather than modifying the original, so discarding the result is
+/// almost always a mistake
+///
+/// ```rust,compile_fail
+/// #![deny(unused_must_use)]
+/// use std::time::Duration;
+/// use unisecs::Seconds;
+///
+/// let secs = Seconds::now();
+/// secs + Duration::from_secs(1);
+/// ```
+#[derive(PartialEq, Copy, Clone)]
+#[cfg_attr(
+    feature = "diesel",
+    derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow),
+    diesel(sql_type = diesel::sql_types::Double)
+)]
+#[must_use]
 pub struct Seconds(f64);
 
+/// How far apart two `Seconds` may be while still being considered the same
+/// instant. See [`Seconds::within`](struct.Seconds.html#method.within)
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum Tolerance {
+    /// values may differ by at most this fixed duration, regardless of magnitude
+    Absolute(Duration),
+    /// values may differ by at most this many parts per million of the larger
+    /// magnitude, which scales with the size of the values being compared
+    Relative(f64),
+}
+
+/// Coarse age classification used by retention jobs. See
+/// [`Seconds::retention_bucket`](struct.Seconds.html#method.retention_bucket)
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum RetentionBucket {
+    /// younger than one hour
+    UnderHour,
+    /// younger than one day
+    UnderDay,
+    /// younger than thirty days
+    UnderThirtyDays,
+    /// thirty days or older
+    ThirtyDaysOrOlder,
+}
+
+impl RetentionBucket {
+    /// upper age bounds, exclusive, for each bucket short of the oldest
+    pub const THRESHOLDS: [(Duration, RetentionBucket); 3] = [
+        (Duration::from_secs(60 * 60), RetentionBucket::UnderHour),
+        (Duration::from_secs(24 * 60 * 60), RetentionBucket::UnderDay),
+        (
+            Duration::from_secs(30 * 24 * 60 * 60),
+            RetentionBucket::UnderThirtyDays,
+        ),
+    ];
+}
+
+/// How a value between two multiples of a unit is resolved to one of them. See
+/// [`Seconds::quantize_with`](struct.Seconds.html#method.quantize_with)
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum RoundingMode {
+    /// toward zero
+    Trunc,
+    /// toward negative infinity
+    Floor,
+    /// toward positive infinity
+    Ceil,
+    /// to the nearest multiple, with half way cases away from zero
+    Nearest,
+    /// to the nearest multiple, with half way cases to the nearest even multiple
+    NearestEven,
+}
+
+impl RoundingMode {
+    fn apply(
+        self,
+        value: f64,
+    ) -> f64 {
+        match self {
+            RoundingMode::Trunc => value.trunc(),
+            RoundingMode::Floor => value.floor(),
+            RoundingMode::Ceil => value.ceil(),
+            RoundingMode::Nearest => value.round(),
+            RoundingMode::NearestEven => value.round_ties_even(),
+        }
+    }
+}
+
+/// Where [`Seconds::now_with_source`](struct.Seconds.html#method.now_with_source)
+/// reads the current time from
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ClockSource {
+    /// the system's wall clock, precise but subject to adjustments such as NTP steps
+    Wall,
+    /// a cheaper, coarser wall clock read, see
+    /// [`Seconds::now_coarse`](struct.Seconds.html#method.now_coarse)
+    Coarse,
+    /// the wall clock as read once per process, advanced by the monotonic clock, so
+    /// readings never step backward but drift from any later wall clock adjustment
+    MonotonicAnchored,
+}
+
+/// An interval broken down into fixed size units, each the remainder after the
+/// larger units. See [`Seconds::relative_parts`](struct.Seconds.html#method.relative_parts)
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct RelativeParts {
+    /// whole 7 day weeks
+    pub weeks: u64,
+    /// whole 24 hour days, less than 7
+    pub days: u8,
+    /// whole hours, less than 24
+    pub hours: u8,
+    /// whole minutes, less than 60
+    pub minutes: u8,
+    /// whole seconds, less than 60
+    pub seconds: u8,
+    /// nanoseconds, less than 1_000_000_000
+    pub subsec_nanos: u32,
+}
+
+/// An inclusive range of time to draw uniformly distributed timestamps from
+///
+/// With the `rand` feature this is a `rand::distributions::Distribution<Seconds>`
+/// sampling within `[lo, hi]`. Sampling panics if `lo` is later than `hi` or
+/// either is not finite
+///
+/// ```rust
+/// # #[cfg(feature = "rand")]
+/// # {
+/// use rand::Rng;
+/// use unisecs::{Seconds, SecondsRange};
+/// let lo = "1545136342".parse().expect("invalid seconds");
+/// let hi = "1545139942".parse().expect("invalid seconds");
+/// let secs: Seconds = rand::thread_rng().sample(SecondsRange { lo, hi });
+/// assert!(f64::from(secs) >= f64::from(lo) && f64::from(secs) <= f64::from(hi));
+/// # }
+/// ```
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct SecondsRange {
+    /// the earliest timestamp which may be sampled
+    pub lo: Seconds,
+    /// the latest timestamp which may be sampled
+    pub hi: Seconds,
+}
+
+#[cfg(feature = "rand")]
+impl rand::distributions::Distribution<Seconds> for SecondsRange {
+    fn sample<R>(
+        &self,
+        rng: &mut R,
+    ) -> Seconds
+    where
+        R: rand::Rng + ?Sized,
+    {
+        Seconds(rng.gen_range(self.lo.0..=self.hi.0))
+    }
+}
+
+/// An error returned when a string can not be parsed into `Seconds`
+#[derive(Debug, PartialEq, Clone)]
+pub struct ParseError {
+    input: String,
+    expected: &'static str,
+}
+
+impl ParseError {
+    pub(crate) fn new(
+        input: &str,
+        expected: &'static str,
+    ) -> Self {
+        ParseError {
+            input: input.into(),
+            expected,
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter,
+    ) -> fmt::Result {
+        write!(f, "invalid {}: {:?}", self.expected, self.input)
+    }
+}
+
+impl Error for ParseError {}
+
+/// An error returned when bytes can not be decoded into `Seconds`. See
+/// [`Seconds::from_bytes_auto`](struct.Seconds.html#method.from_bytes_auto) and
+/// [`Seconds::from_varint_delta`](struct.Seconds.html#method.from_varint_delta)
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct DecodeError(DecodeErrorKind);
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+enum DecodeErrorKind {
+    Length(usize),
+    Varint,
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter,
+    ) -> fmt::Result {
+        match self.0 {
+            DecodeErrorKind::Length(len) => write!(f, "expected 4 or 8 bytes, got {}", len),
+            DecodeErrorKind::Varint => f.write_str("truncated or overlong varint"),
+        }
+    }
+}
+
+impl Error for DecodeError {}
+
+/// An error returned when seconds fall outside of an accepted range. See
+/// [`Seconds::from_secs_in_range`](struct.Seconds.html#method.from_secs_in_range)
+#[derive(Debug, PartialEq, Clone)]
+pub struct RangeError {
+    secs: f64,
+    lo: Seconds,
+    hi: Seconds,
+}
+
+impl fmt::Display for RangeError {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter,
+    ) -> fmt::Result {
+        write!(
+            f,
+            "{} seconds is outside of the range [{}, {}]",
+            self.secs, self.lo, self.hi
+        )
+    }
+}
+
+impl Error for RangeError {}
+
+/// Asserts that two `Seconds` are within a `Duration` of each other, panicking
+/// with both values and their difference otherwise
+///
+/// ```rust
+/// use std::time::Duration;
+/// use unisecs::{assert_seconds_within, Seconds};
+///
+/// let started = Seconds::now();
+/// assert_seconds_within!(Seconds::now(), started, Duration::from_secs(1));
+/// ```
+#[macro_export]
+macro_rules! assert_seconds_within {
+    ($actual:expr, $expected:expr, $tolerance:expr $(,)?) => {{
+        let (actual, expected): ($crate::Seconds, $crate::Seconds) = ($actual, $expected);
+        let tolerance: ::std::time::Duration = $tolerance;
+        let diff = f64::from(actual) - f64::from(expected);
+        if diff.is_nan() || diff.abs() > tolerance.as_secs_f64() {
+            panic!(
+                "assertion failed: seconds not within {:?}\n  actual: {}\nexpected: {}\n    diff: {}s",
+                tolerance, actual, expected, diff
+            );
+        }
+    }};
+}
+
+/// Formats as `Seconds(1545136342.711932)`. The alternate form, `{:#?}`, also
+/// includes the exact bit pattern of the inner `f64` and whether it is finite,
+/// which helps when debugging precision issues
+impl fmt::Debug for Seconds {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter,
+    ) -> fmt::Result {
+        if f.alternate() {
+            f.debug_struct("Seconds")
+                .field("secs", &self.0)
+                .field("bits", &format_args!("{:#018x}", self.0.to_bits()))
+                .field("finite", &self.0.is_finite())
+                .finish()
+        } else {
+            f.debug_tuple("Seconds").field(&self.0).finish()
+        }
+    }
+}
+
 impl fmt::Display for Seconds {
     fn fmt(
         &self,
@@ -60,8 +486,25 @@ impl fmt::Display for Seconds {
 }
 
 impl Seconds {
+    /// the unix epoch itself, 1-1-1970 midnight
+    pub const EPOCH: Seconds = Seconds(0.0);
+
+    /// the latest instant at which every whole second is still exactly representable,
+    /// `2^53 - 1` seconds after the epoch, some 285 million years from now
+    pub const MAX: Seconds = Seconds(9_007_199_254_740_991.0);
+
+    /// the earliest instant at which every whole second is still exactly
+    /// representable, `2^53 - 1` seconds before the epoch
+    pub const MIN: Seconds = Seconds(-9_007_199_254_740_991.0);
+
     /// return the current time in seconds since the unix epoch (1-1-1970 midnight)
     pub fn now() -> Self {
+        #[cfg(feature = "test-clock")]
+        {
+            if let Some(secs) = frozen::get() {
+                return secs;
+            }
+        }
         Self::from_duration(
             SystemTime::now()
                 .duration_since(UNIX_EPOCH)
@@ -69,186 +512,3772 @@ impl Seconds {
         )
     }
 
-    /// truncate epoch time to remove fractional seconds
-    pub fn trunc(self) -> Self {
-        Self(self.0.trunc())
+    /// return the current time in seconds since the unix epoch using a coarse clock
+    ///
+    /// This trades precision for a cheaper clock read, which is useful on hot paths
+    /// like stamping log events. With the `coarse-clock` feature enabled on Linux
+    /// this reads `CLOCK_REALTIME_COARSE`, which is typically only precise to
+    /// within ~1-4ms depending on the kernel's tick rate. Elsewhere this falls
+    /// back to [`Seconds::now`](struct.Seconds.html#method.now)
+    pub fn now_coarse() -> Self {
+        #[cfg(feature = "test-clock")]
+        {
+            if let Some(secs) = frozen::get() {
+                return secs;
+            }
+        }
+        #[cfg(all(feature = "coarse-clock", target_os = "linux"))]
+        {
+            if let Some(secs) = coarse::now() {
+                return secs;
+            }
+        }
+        Self::now()
     }
 
-    /// transformation is kept private as we can make no guarantees
-    /// about whether a provided duration is anchored in any way to
-    /// unix time
-    fn from_duration(dur: Duration) -> Self {
-        Seconds(dur.as_secs() as f64 + (f64::from(dur.subsec_nanos()) / 1.0e9))
+    /// stop the clock for the current thread, so that
+    /// [`Seconds::now`](struct.Seconds.html#method.now) and
+    /// [`Seconds::now_coarse`](struct.Seconds.html#method.now_coarse) return `now`
+    /// until [`Seconds::unfreeze`](struct.Seconds.html#method.unfreeze) is called
+    ///
+    /// The frozen time is thread local. Other threads, including any spawned by the
+    /// code under test, continue to read the system clock
+    #[cfg(feature = "test-clock")]
+    pub fn freeze(now: Seconds) {
+        frozen::set(Some(now))
     }
-}
 
-impl Default for Seconds {
-    fn default() -> Self {
-        Seconds::now()
+    /// restart the clock for the current thread after
+    /// [`Seconds::freeze`](struct.Seconds.html#method.freeze)
+    #[cfg(feature = "test-clock")]
+    pub fn unfreeze() {
+        frozen::set(None)
     }
-}
 
-impl Into<f64> for Seconds {
-    fn into(self) -> f64 {
-        let Seconds(secs) = self;
-        secs
+    /// return the current time in seconds since the unix epoch read from `source`
+    ///
+    /// `Seconds::now()` is shorthand for `ClockSource::Wall`
+    pub fn now_with_source(source: ClockSource) -> Self {
+        match source {
+            ClockSource::Wall => Self::now(),
+            ClockSource::Coarse => Self::now_coarse(),
+            ClockSource::MonotonicAnchored => Self::now_monotonic_anchored(),
+        }
     }
-}
 
-/// Similar to `date -v+1S +%s`
-impl Add<Duration> for Seconds {
-    type Output = Seconds;
-    fn add(
-        self,
-        rhs: Duration,
-    ) -> Self::Output {
-        let lhs: Duration = self.into();
-        Seconds::from_duration(lhs + rhs)
+    /// return a best-effort estimate of the granularity of the system wall clock
+    /// read by [`Seconds::now`](struct.Seconds.html#method.now)
+    ///
+    /// The clock is read repeatedly and the smallest nonzero step between two reads
+    /// is reported, so the estimate can be coarser than the true resolution on a
+    /// busy machine. It is measured once and cached. Should the clock never appear
+    /// to move, one second is reported
+    ///
+    /// Probing stops after 64 steps or 20 milliseconds, whichever comes first, so
+    /// the first call spends at most about 20 milliseconds reading the clock and
+    /// later calls are free
+    #[must_use]
+    pub fn clock_resolution() -> Duration {
+        const BUDGET: Duration = Duration::from_millis(20);
+        static RESOLUTION: OnceLock<Duration> = OnceLock::new();
+        *RESOLUTION.get_or_init(|| {
+            let (mut smallest, mut steps) = (None::<Duration>, 0);
+            let started = Instant::now();
+            let mut last = SystemTime::now();
+            while started.elapsed() < BUDGET {
+                let now = SystemTime::now();
+                if let Ok(step) = now.duration_since(last) {
+                    if step > Duration::from_secs(0) {
+                        smallest = Some(smallest.map_or(step, |s| s.min(step)));
+                        steps += 1;
+                        if steps == 64 {
+                            break;
+                        }
+                    }
+                }
+                last = now;
+            }
+            smallest.unwrap_or(Duration::from_secs(1))
+        })
     }
-}
 
-/// Similar to `date -v-1S +%s`
-impl Sub<Duration> for Seconds {
-    type Output = Seconds;
-    fn sub(
-        self,
-        rhs: Duration,
-    ) -> Self::Output {
-        let lhs: Duration = self.into();
-        Seconds::from_duration(lhs - rhs)
+    fn now_monotonic_anchored() -> Self {
+        #[cfg(feature = "test-clock")]
+        {
+            if let Some(secs) = frozen::get() {
+                return secs;
+            }
+        }
+        static ANCHOR: OnceLock<(Seconds, Instant)> = OnceLock::new();
+        let (wall, instant) = ANCHOR.get_or_init(|| (Self::now(), Instant::now()));
+        *wall + instant.elapsed()
     }
-}
 
-impl Into<Duration> for Seconds {
-    fn into(self) -> Duration {
-        let Seconds(secs) = self;
-        Duration::new(secs.trunc() as u64, (secs.fract() * 1.0e9) as u32)
+    /// return the current time floored to a multiple of `unit`, for stamping many
+    /// events at a deliberately coarse shared resolution
+    ///
+    /// This is shorthand for `Seconds::now().truncate_to_unit(unit)`
+    pub fn now_truncated_to(unit: Duration) -> Self {
+        Self::now_truncated_to_with(&SystemClock, unit)
     }
-}
 
-#[cfg(feature = "serde")]
-struct SecondsVisitor;
+    /// same as [`Seconds::now_truncated_to`](struct.Seconds.html#method.now_truncated_to)
+    /// but reading the current time from the provided clock
+    pub fn now_truncated_to_with<C>(
+        clock: &C,
+        unit: Duration,
+    ) -> Self
+    where
+        C: Clock + ?Sized,
+    {
+        clock.now().truncate_to_unit(unit)
+    }
 
-#[cfg(feature = "serde")]
-impl<'de> de::Visitor<'de> for SecondsVisitor {
-    type Value = Seconds;
+    /// return `n` copies of the current time, reading the clock exactly once
+    ///
+    /// This is useful for stamping a batch of events which logically happened at
+    /// the same time, every value in the batch is guaranteed to be identical
+    #[must_use]
+    pub fn now_many(n: usize) -> Vec<Self> {
+        vec![Self::now(); n]
+    }
 
-    fn expecting(
-        &self,
-        formatter: &mut fmt::Formatter,
-    ) -> fmt::Result {
-        formatter.write_str("floating point seconds")
+    /// truncate epoch time to remove fractional seconds
+    pub fn trunc(self) -> Self {
+        self.quantize_with(Duration::from_secs(1), RoundingMode::Trunc)
     }
-    fn visit_f64<E>(
-        self,
-        value: f64,
-    ) -> Result<Seconds, E>
-    where
-        E: de::Error,
-    {
-        Ok(Seconds(value))
+
+    /// round epoch time to the nearest whole second, rounding half way cases away
+    /// from zero
+    pub fn round(self) -> Self {
+        self.quantize_with(Duration::from_secs(1), RoundingMode::Nearest)
     }
-}
 
-#[cfg(feature = "serde")]
-impl ser::Serialize for Seconds {
-    fn serialize<S>(
-        &self,
-        serializer: S,
-    ) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        let Seconds(seconds) = self;
-        serializer.serialize_f64(*seconds)
+    /// round epoch time to the nearest whole second, rounding half way cases to the
+    /// nearest even second
+    ///
+    /// Unlike [`Seconds::round`](struct.Seconds.html#method.round), ties don't
+    /// always move away from zero, so rounding many values doesn't bias their sum
+    pub fn round_ties_even(self) -> Self {
+        self.quantize_with(Duration::from_secs(1), RoundingMode::NearestEven)
     }
-}
 
-#[cfg(feature = "serde")]
-impl<'de> de::Deserialize<'de> for Seconds {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-    where
-        D: de::Deserializer<'de>,
-    {
-        deserializer.deserialize_f64(SecondsVisitor)
+    /// round epoch time down to the whole second at or before it
+    pub fn floor(self) -> Self {
+        self.quantize_with(Duration::from_secs(1), RoundingMode::Floor)
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::Seconds;
-    use std::time::Duration;
+    /// round epoch time up to the whole second at or after it
+    pub fn ceil(self) -> Self {
+        self.quantize_with(Duration::from_secs(1), RoundingMode::Ceil)
+    }
 
-    #[test]
-    fn seconds_default() {
-        let (now, default) = (Seconds::default(), Seconds::now());
-        assert_eq!(now.trunc(), default.trunc());
+    /// truncate epoch time in place to remove fractional seconds
+    pub fn trunc_mut(&mut self) {
+        *self = self.trunc()
     }
 
-    #[test]
-    fn seconds_deref() {
-        let secs = Seconds(1_545_136_342.711_932);
-        let _f: f64 = secs.into();
+    /// round epoch time in place to the nearest whole second
+    pub fn round_mut(&mut self) {
+        *self = self.round()
     }
 
-    #[test]
-    fn seconds_display() {
-        let secs = Seconds(1_545_136_342.711_932);
-        assert_eq!(format!("{}", secs), "1545136342.711932");
+    /// round epoch time in place down to the whole second at or before it
+    pub fn floor_mut(&mut self) {
+        *self = self.floor()
     }
 
-    #[test]
-    fn seconds_duration_interop() {
-        let secs = Seconds(1_545_136_342.711_932);
-        let duration: Duration = secs.into();
-        assert_eq!(duration.as_secs(), 1_545_136_342);
+    /// round epoch time in place up to the whole second at or after it
+    pub fn ceil_mut(&mut self) {
+        *self = self.ceil()
     }
 
-    #[test]
-    fn seconds_add_duration() {
+    /// linearly interpolate between `start` and `end`
+    ///
+    /// `t` is the fraction of the way from `start` to `end` and is clamped to `[0, 1]`
+    /// so the result never extrapolates beyond either end
+    pub fn lerp(
+        start: Seconds,
+        end: Seconds,
+        t: f64,
+    ) -> Self {
+        let t = t.clamp(0.0, 1.0);
+        Seconds(start.0 + (end.0 - start.0) * t)
+    }
+
+    /// return the fraction of the way `self` is between `start` and `end`
+    ///
+    /// This is the inverse of [`Seconds::lerp`](struct.Seconds.html#method.lerp). The
+    /// result is clamped to `[0, 1]`. When `start` and `end` are the same instant
+    /// there is nothing left to wait for so this returns `1.0`
+    #[must_use]
+    pub fn progress(
+        &self,
+        start: Seconds,
+        end: Seconds,
+    ) -> f64 {
+        let span = end.0 - start.0;
+        if span == 0.0 {
+            return 1.0;
+        }
+        ((self.0 - start.0) / span).clamp(0.0, 1.0)
+    }
+
+    /// split seconds into whole seconds and nanoseconds following protobuf's
+    /// `Timestamp` normalization rules
+    ///
+    /// Whole seconds are floored so the nanos component is always within
+    /// `[0, 1_000_000_000)`, even for timestamps before the epoch
+    #[must_use]
+    pub fn to_timestamp_parts(&self) -> (i64, i32) {
+        let mut secs = self.0.floor() as i64;
+        let mut nanos = ((self.0 - self.0.floor()) * 1.0e9).round() as i32;
+        // rounding the fraction may carry into the next whole second
+        if nanos >= 1_000_000_000 {
+            secs += 1;
+            nanos -= 1_000_000_000;
+        }
+        (secs, nanos)
+    }
+
+    /// join protobuf `Timestamp` style whole seconds and nanoseconds
+    ///
+    /// Nanos outside of `[0, 1_000_000_000)` are normalized by carrying into
+    /// whole seconds
+    pub fn from_timestamp_parts(
+        seconds: i64,
+        nanos: i32,
+    ) -> Self {
+        join_parts(seconds, i64::from(nanos), 1_000_000_000)
+    }
+
+    /// split an interval into whole seconds and nanoseconds following protobuf's
+    /// `Duration` normalization rules
+    ///
+    /// Unlike [`Seconds::to_timestamp_parts`](struct.Seconds.html#method.to_timestamp_parts),
+    /// whole seconds are truncated toward zero so both parts share the same sign,
+    /// with nanos always within `(-1_000_000_000, 1_000_000_000)`
+    #[must_use]
+    pub fn to_proto_duration_parts(&self) -> (i64, i32) {
+        let mut secs = self.0.trunc() as i64;
+        let mut nanos = (self.0.fract() * 1.0e9).round() as i32;
+        // rounding the fraction may carry into the next whole second
+        if nanos.abs() >= 1_000_000_000 {
+            secs += i64::from(nanos.signum());
+            nanos -= nanos.signum() * 1_000_000_000;
+        }
+        (secs, nanos)
+    }
+
+    /// join protobuf `Duration` style whole seconds and nanoseconds
+    ///
+    /// Parts are summed, so nanos of a magnitude of a second or more carry into
+    /// whole seconds
+    pub fn from_proto_duration_parts(
+        seconds: i64,
+        nanos: i32,
+    ) -> Self {
+        join_parts(seconds, i64::from(nanos), 1_000_000_000)
+    }
+
+    /// split seconds into the `tv_sec` and `tv_nsec` fields of a C `struct timespec`
+    ///
+    /// As with [`Seconds::to_timestamp_parts`](struct.Seconds.html#method.to_timestamp_parts)
+    /// whole seconds are floored and nanoseconds which round up to a full second carry
+    /// into `tv_sec`, so `tv_nsec` is always less than `1_000_000_000`
+    #[must_use]
+    pub fn to_timespec_parts(&self) -> (i64, u32) {
+        let (secs, nanos) = self.to_timestamp_parts();
+        (secs, nanos as u32)
+    }
+
+    /// join the `tv_sec` and `tv_nsec` fields of a C `struct timespec`
+    ///
+    /// Nanoseconds of a second or more carry into whole seconds
+    pub fn from_timespec_parts(
+        sec: i64,
+        nsec: u32,
+    ) -> Self {
+        join_parts(sec, i64::from(nsec), 1_000_000_000)
+    }
+
+    /// split seconds into the `tv_sec` and `tv_usec` fields of a C `struct timeval`
+    ///
+    /// As with [`Seconds::to_timespec_parts`](struct.Seconds.html#method.to_timespec_parts),
+    /// whole seconds are floored so `tv_usec` is always within `[0, 1_000_000)`.
+    /// Microseconds are rounded to the nearest, carrying into whole seconds
+    #[must_use]
+    pub fn to_timeval_parts(&self) -> (i64, i64) {
+        let mut sec = self.0.floor() as i64;
+        let mut usec = ((self.0 - self.0.floor()) * 1.0e6).round() as i64;
+        if usec >= 1_000_000 {
+            sec += 1;
+            usec -= 1_000_000;
+        }
+        (sec, usec)
+    }
+
+    /// join the `tv_sec` and `tv_usec` fields of a C `struct timeval`
+    ///
+    /// Microseconds outside of `[0, 1_000_000)` are normalized by carrying into
+    /// whole seconds
+    pub fn from_timeval_parts(
+        sec: i64,
+        usec: i64,
+    ) -> Self {
+        join_parts(sec, usec, 1_000_000)
+    }
+
+    /// return true if these seconds are neither infinite nor NaN
+    #[must_use]
+    pub fn is_finite(&self) -> bool {
+        self.0.is_finite()
+    }
+
+    /// return true if these seconds are NaN
+    #[must_use]
+    pub fn is_nan(&self) -> bool {
+        self.0.is_nan()
+    }
+
+    /// return true if these seconds are positive or negative infinity
+    #[must_use]
+    pub fn is_infinite(&self) -> bool {
+        self.0.is_infinite()
+    }
+
+    /// return these seconds unchanged, checking in debug builds that they are finite
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, panics at the caller's location if these seconds are
+    /// infinite or `NaN`. In release builds the check compiles away
+    #[track_caller]
+    pub fn debug_checked(self) -> Self {
+        debug_assert!(self.is_finite(), "non-finite Seconds: {:?}", self.0);
+        self
+    }
+
+    /// collapse negative zero into positive zero
+    ///
+    /// `-0.0` and `0.0` compare equal but have distinct bit patterns, which otherwise
+    /// leaks into hashing and serialized output
+    pub fn normalize_zero(self) -> Self {
+        if self.0 == 0.0 {
+            Seconds(0.0)
+        } else {
+            self
+        }
+    }
+
+    /// return true if `self` and `other` are within the given tolerance of each other
+    #[must_use]
+    pub fn within(
+        &self,
+        other: Seconds,
+        tol: Tolerance,
+    ) -> bool {
+        let diff = (self.0 - other.0).abs();
+        match tol {
+            Tolerance::Absolute(dur) => diff <= dur.as_secs_f64(),
+            Tolerance::Relative(ppm) => diff <= self.0.abs().max(other.0.abs()) * ppm / 1.0e6,
+        }
+    }
+
+    /// subtract a duration, saturating at the epoch, and report whether saturation
+    /// occurred
+    ///
+    /// This is useful for flagging anomalies, such as clock skew, which would
+    /// otherwise be hidden by the clamped value. Only results crossing the epoch
+    /// from seconds at or after it saturate. Seconds already before the epoch, and
+    /// non-finite seconds, have the duration subtracted as is, so the result never
+    /// moves forward in time
+    pub fn sub_saturating_reported(
+        self,
+        rhs: Duration,
+    ) -> (Seconds, bool) {
+        if self.0 < 0.0 || !self.is_finite() {
+            return (Seconds(self.0 - rhs.as_secs_f64()), false);
+        }
+        let lhs: Duration = self.into();
+        match lhs.checked_sub(rhs) {
+            Some(dur) => (Seconds::from_duration(dur), false),
+            None => (Seconds::EPOCH, true),
+        }
+    }
+
+    /// classify the age of these seconds relative to `now` for retention purposes
+    ///
+    /// Timestamps in the future of `now` are treated as having no age
+    #[must_use]
+    pub fn retention_bucket(
+        &self,
+        now: Seconds,
+    ) -> RetentionBucket {
+        let age = (now.0 - self.0).max(0.0);
+        RetentionBucket::THRESHOLDS
+            .iter()
+            .find(|(limit, _)| age < limit.as_secs_f64())
+            .map(|(_, bucket)| *bucket)
+            .unwrap_or(RetentionBucket::ThirtyDaysOrOlder)
+    }
+
+    /// truncate the fractional seconds to at most `digits` decimal places
+    ///
+    /// Unlike rounding, this always truncates toward zero so `clamp_subsec_precision(3)`
+    /// caps values at millisecond precision without ever moving them forward
+    pub fn clamp_subsec_precision(
+        self,
+        digits: u32,
+    ) -> Self {
+        let scale = fixed_point_factor(digits);
+        let scaled = self.0 * scale;
+        // beyond f64's integer precision truncating is a no-op, and dividing back
+        // down would only introduce rounding errors
+        if scaled.is_nan() || scaled.abs() > Self::MAX.0 {
+            return self;
+        }
+        Seconds(scaled.trunc() / scale)
+    }
+
+    /// return the arithmetic mean of `values`, or `None` if there are none
+    ///
+    /// Offsets are averaged relative to the first value rather than summing raw
+    /// epoch values, which keeps large timestamps from losing precision
+    #[must_use]
+    pub fn average(values: &[Seconds]) -> Option<Self> {
+        let base = values.first()?.0;
+        let offset = values.iter().map(|secs| secs.0 - base).sum::<f64>() / values.len() as f64;
+        Some(Seconds(base + offset))
+    }
+
+    /// add a uniformly distributed random offset within `[0, max]` to these seconds
+    ///
+    /// The random number generator is provided by the caller, so jitter can be made
+    /// reproducible in tests with a seeded generator
+    #[cfg(feature = "rand")]
+    pub fn with_jitter(
+        self,
+        max: Duration,
+        rng: &mut impl rand::Rng,
+    ) -> Self {
+        Seconds(self.0 + rng.gen_range(0.0..=max.as_secs_f64()))
+    }
+
+    /// resolve these seconds to a multiple of `unit` since the epoch using the
+    /// provided rounding mode
+    ///
+    /// The whole second shorthands, such as [`Seconds::round`](struct.Seconds.html#method.round),
+    /// are equivalent to a one second `unit`. A zero `unit` leaves the value unchanged
+    pub fn quantize_with(
+        self,
+        unit: Duration,
+        mode: RoundingMode,
+    ) -> Self {
+        let unit = unit.as_secs_f64();
+        if unit == 0.0 {
+            return self;
+        }
+        Seconds(mode.apply(self.0 / unit) * unit)
+    }
+
+    /// resolve these seconds to a point on a grid of `period` spaced ticks offset from
+    /// the epoch by `phase`, using the provided rounding mode
+    ///
+    /// This generalizes [`Seconds::quantize_with`](struct.Seconds.html#method.quantize_with)
+    /// to grids which are not epoch aligned, e.g. a 30 minute period with a 7 minute
+    /// phase ticks at :07 and :37 past each hour
+    ///
+    /// # Panics
+    ///
+    /// Panics if `phase` is not less than `period`
+    pub fn snap_to_grid(
+        self,
+        period: Duration,
+        phase: Duration,
+        mode: RoundingMode,
+    ) -> Self {
+        assert!(phase < period, "grid phase must be less than the period");
+        let phase = phase.as_secs_f64();
+        let snapped = Seconds(self.0 - phase).quantize_with(period, mode);
+        Seconds(snapped.0 + phase)
+    }
+
+    /// truncate down to the largest multiple of `unit` which does not exceed these seconds
+    ///
+    /// This generalizes [`Seconds::trunc`](struct.Seconds.html#method.trunc) to
+    /// arbitrary units, e.g. rolling timestamps up into 5 minute buckets. A zero
+    /// `unit` leaves the value unchanged
+    pub fn truncate_to_unit(
+        self,
+        unit: Duration,
+    ) -> Self {
+        self.quantize_with(unit, RoundingMode::Floor)
+    }
+
+    /// subtract a duration, returning `None` where
+    /// [`Seconds::sub_saturating_reported`](struct.Seconds.html#method.sub_saturating_reported)
+    /// would saturate, when the result would cross from at or after the epoch to
+    /// before it
+    #[must_use]
+    pub fn checked_sub(
+        self,
+        rhs: Duration,
+    ) -> Option<Self> {
+        match self.sub_saturating_reported(rhs) {
+            (secs, false) => Some(secs),
+            (_, true) => None,
+        }
+    }
+
+    /// scale these seconds by `factor`, returning `None` if the result is not finite
+    #[must_use]
+    pub fn checked_mul(
+        self,
+        factor: f64,
+    ) -> Option<Self> {
+        Some(Seconds(self.0 * factor)).filter(Seconds::is_finite)
+    }
+
+    /// divide these seconds by `divisor`, returning `None` if the result is not
+    /// finite, as when dividing by zero
+    #[must_use]
+    pub fn checked_div(
+        self,
+        divisor: f64,
+    ) -> Option<Self> {
+        Some(Seconds(self.0 / divisor)).filter(Seconds::is_finite)
+    }
+
+    /// parse a hexadecimal integer number of seconds, as emitted by some embedded
+    /// devices, with or without a leading `0x`
+    pub fn from_hex_secs(s: &str) -> Result<Self, ParseError> {
+        let digits = s
+            .strip_prefix("0x")
+            .or_else(|| s.strip_prefix("0X"))
+            .unwrap_or(s);
+        u64::from_str_radix(digits, 16)
+            .map(|secs| Seconds(secs as f64))
+            .map_err(|_| ParseError::new(s, "hexadecimal seconds"))
+    }
+
+    /// return true if the whole seconds are below 2^53 in magnitude, so that every
+    /// whole second is exactly representable, even once parsed as a JavaScript number
+    #[must_use]
+    pub fn fits_in_f64_integer(&self) -> bool {
+        self.0.abs() < 9_007_199_254_740_992.0
+    }
+
+    /// clamp these seconds to within [`Seconds::MIN`](struct.Seconds.html#associatedconstant.MIN)
+    /// and [`Seconds::MAX`](struct.Seconds.html#associatedconstant.MAX), the range of
+    /// exactly representable whole seconds. `NaN` is left unchanged
+    pub fn clamp_to_i53(self) -> Self {
+        Seconds(self.0.clamp(Seconds::MIN.0, Seconds::MAX.0))
+    }
+
+    /// create seconds, rejecting values outside of the inclusive range `[lo, hi]`
+    ///
+    /// This is an ingestion guard against obvious garbage, such as a millisecond
+    /// timestamp mistaken for seconds. `NaN` is never in range
+    pub fn from_secs_in_range(
+        secs: f64,
+        lo: Seconds,
+        hi: Seconds,
+    ) -> Result<Self, RangeError> {
+        if (lo.0..=hi.0).contains(&secs) {
+            Ok(Seconds(secs))
+        } else {
+            Err(RangeError { secs, lo, hi })
+        }
+    }
+
+    /// parse a decimal number with an `s`, `ms`, `us` or `ns` unit suffix, such as
+    /// `1.5s` or `1500ms`, into seconds. A bare number is read as seconds
+    ///
+    /// The result is whatever the value denotes to the caller, either seconds since
+    /// the epoch or a relative interval, only the unit is converted
+    pub fn parse_with_unit(s: &str) -> Result<Self, ParseError> {
+        let (number, scale) = [("ms", 1.0e3), ("us", 1.0e6), ("ns", 1.0e9), ("s", 1.0)]
+            .iter()
+            .find_map(|&(unit, scale)| s.strip_suffix(unit).map(|number| (number, scale)))
+            .unwrap_or((s, 1.0));
+        match number.parse::<f64>() {
+            Ok(value) if value.is_finite() => Ok(Seconds(value / scale)),
+            _ => Err(ParseError::new(s, "seconds with an s, ms, us or ns unit")),
+        }
+    }
+
+    /// parse an integer or decimal number of milliseconds since the epoch, as sent
+    /// by producers which quote millisecond timestamps to avoid losing precision
+    pub fn from_millis_str(s: &str) -> Result<Self, ParseError> {
+        // integers split into whole seconds and a remainder before converting so
+        // precision isn't lost on large values
+        if let Ok(millis) = s.parse::<i64>() {
+            let secs = millis.div_euclid(1_000) as f64;
+            return Ok(Seconds(secs + millis.rem_euclid(1_000) as f64 / 1.0e3));
+        }
+        match s.parse::<f64>() {
+            Ok(millis) if millis.is_finite() => Ok(Seconds(millis / 1.0e3)),
+            _ => Err(ParseError::new(s, "milliseconds")),
+        }
+    }
+
+    /// return true if `self` and `other` fall within the same whole second
+    #[must_use]
+    pub fn eq_secs(
+        &self,
+        other: Seconds,
+    ) -> bool {
+        self.trunc() == other.trunc()
+    }
+
+    /// encode the bits of the inner `f64` as little-endian bytes
+    ///
+    /// This round-trips exactly through
+    /// [`Seconds::from_le_bytes`](struct.Seconds.html#method.from_le_bytes), including
+    /// the bit patterns of NaNs
+    #[must_use]
+    pub fn to_le_bytes(&self) -> [u8; 8] {
+        self.0.to_bits().to_le_bytes()
+    }
+
+    /// decode seconds from the little-endian bytes of an `f64`
+    pub fn from_le_bytes(bytes: [u8; 8]) -> Self {
+        Seconds(f64::from_bits(u64::from_le_bytes(bytes)))
+    }
+
+    /// encode the bits of the inner `f64` as big-endian bytes
+    ///
+    /// This round-trips exactly through
+    /// [`Seconds::from_be_bytes`](struct.Seconds.html#method.from_be_bytes), including
+    /// the bit patterns of NaNs
+    #[must_use]
+    pub fn to_be_bytes(&self) -> [u8; 8] {
+        self.0.to_bits().to_be_bytes()
+    }
+
+    /// decode seconds from the big-endian bytes of an `f64`
+    pub fn from_be_bytes(bytes: [u8; 8]) -> Self {
+        Seconds(f64::from_bits(u64::from_be_bytes(bytes)))
+    }
+
+    /// decode seconds from either a 4 byte big-endian `u32` number of whole seconds,
+    /// as written by older binary formats, or the 8 big-endian bytes of an `f64`
+    pub fn from_bytes_auto(bytes: &[u8]) -> Result<Self, DecodeError> {
+        if let Ok(bytes) = <[u8; 4]>::try_from(bytes) {
+            Ok(Seconds(f64::from(u32::from_be_bytes(bytes))))
+        } else if let Ok(bytes) = <[u8; 8]>::try_from(bytes) {
+            Ok(Seconds::from_be_bytes(bytes))
+        } else {
+            Err(DecodeError(DecodeErrorKind::Length(bytes.len())))
+        }
+    }
+
+    /// encode the difference from `base` as a LEB128 varint of `10^-scale` second
+    /// units, rounded to the nearest unit, for compact storage of timestamps which
+    /// cluster around a known base
+    ///
+    /// The signed delta is zigzag encoded first, so small differences either side of
+    /// `base` take a single byte. Deltas beyond the range of an `i64` saturate, as
+    /// with [`Seconds::to_fixed_point`](struct.Seconds.html#method.to_fixed_point)
+    #[must_use]
+    pub fn to_varint_delta(
+        &self,
+        base: Seconds,
+        scale: u32,
+    ) -> Vec<u8> {
+        let delta = Seconds(self.0 - base.0).to_fixed_point(scale, RoundingMode::Nearest);
+        let mut zigzag = ((delta << 1) ^ (delta >> 63)) as u64;
+        let mut bytes = Vec::with_capacity(10);
+        loop {
+            let byte = (zigzag & 0x7f) as u8;
+            zigzag >>= 7;
+            if zigzag == 0 {
+                bytes.push(byte);
+                return bytes;
+            }
+            bytes.push(byte | 0x80);
+        }
+    }
+
+    /// decode seconds from the start of bytes written by
+    /// [`Seconds::to_varint_delta`](struct.Seconds.html#method.to_varint_delta) with
+    /// the same `base` and `scale`, returning them with the number of bytes read
+    pub fn from_varint_delta(
+        bytes: &[u8],
+        base: Seconds,
+        scale: u32,
+    ) -> Result<(Self, usize), DecodeError> {
+        let mut zigzag = 0u64;
+        for (i, byte) in bytes.iter().enumerate().take(10) {
+            let bits = u64::from(byte & 0x7f);
+            // the tenth byte may only carry the single remaining bit of a u64
+            if i == 9 && bits > 1 {
+                break;
+            }
+            zigzag |= bits << (7 * i);
+            if byte & 0x80 == 0 {
+                let delta = (zigzag >> 1) as i64 ^ -((zigzag & 1) as i64);
+                let secs = Seconds::from_fixed_point(delta, scale);
+                return Ok((Seconds(base.0 + secs.0), i + 1));
+            }
+        }
+        Err(DecodeError(DecodeErrorKind::Varint))
+    }
+
+    /// return the signed, fractional number of seconds elapsed since `earlier`
+    ///
+    /// Unlike a `Duration` the result may be negative when `earlier` is actually
+    /// later than `self`
+    pub fn since(
+        &self,
+        earlier: Seconds,
+    ) -> Self {
+        Seconds(self.0 - earlier.0)
+    }
+
+    /// create seconds from an `f32`
+    ///
+    /// Beware that an `f32` can only represent current epoch timestamps to within
+    /// about 128 seconds, see [`Seconds::to_f32_lossy`](struct.Seconds.html#method.to_f32_lossy)
+    pub fn from_f32(secs: f32) -> Self {
+        Seconds(f64::from(secs))
+    }
+
+    /// convert to an `f32`, accepting a severe loss of precision
+    ///
+    /// An `f32` has a 24 bit significand, so at current epoch values adjacent
+    /// representable `f32`s are 128 seconds apart and every fractional second is
+    /// lost. This is only appropriate where bandwidth matters far more than accuracy
+    #[must_use]
+    pub fn to_f32_lossy(&self) -> f32 {
+        self.0 as f32
+    }
+
+    /// clamp values further in the future than `now` plus `allowance` back to `now`
+    ///
+    /// The allowance tolerates small amounts of clock skew between hosts, such as
+    /// NTP jitter, while still rejecting timestamps which are implausibly far ahead
+    pub fn clamp_future(
+        self,
+        now: Seconds,
+        allowance: Duration,
+    ) -> Self {
+        if self.0 > now.0 + allowance.as_secs_f64() {
+            now
+        } else {
+            self
+        }
+    }
+
+    /// same as [`Seconds::clamp_future`](struct.Seconds.html#method.clamp_future) but
+    /// reading `now` from the provided clock
+    pub fn clamp_future_with<C>(
+        self,
+        clock: &C,
+        allowance: Duration,
+    ) -> Self
+    where
+        C: Clock + ?Sized,
+    {
+        self.clamp_future(clock.now(), allowance)
+    }
+
+    /// return the index of the fixed `window` these seconds fall within, counting
+    /// from `origin`
+    ///
+    /// Timestamps before `origin` have negative indices, with index `-1` being the
+    /// window immediately before it
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window` is zero, just like integer division by zero
+    #[must_use]
+    pub fn bucket_index(
+        &self,
+        origin: Seconds,
+        window: Duration,
+    ) -> i64 {
+        assert!(
+            window != Duration::from_secs(0),
+            "bucket window must be non-zero"
+        );
+        ((self.0 - origin.0) / window.as_secs_f64()).floor() as i64
+    }
+
+    /// create seconds from a duration which the caller guarantees was measured
+    /// from the unix epoch
+    ///
+    /// Typically this is a duration obtained by calling
+    /// `SystemTime::duration_since(UNIX_EPOCH)`. A `Duration` carries no anchor of
+    /// its own, so passing a relative duration, like an elapsed time or a timeout,
+    /// will quietly produce a timestamp near 1970
+    pub fn from_duration_since_epoch(dur: Duration) -> Self {
+        Self::from_duration(dur)
+    }
+
+    /// compare two timestamps, treating values within `epsilon` of each other as equal
+    ///
+    /// Note this is not a total order since it is not transitive, `a` may equal `b`
+    /// and `b` may equal `c` while `a` is still less than `c`. Sorting with it will
+    /// not panic but the resulting order is unspecified
+    #[must_use]
+    pub fn cmp_approx(
+        &self,
+        other: Seconds,
+        epsilon: Duration,
+    ) -> Ordering {
+        if (self.0 - other.0).abs() <= epsilon.as_secs_f64() {
+            Ordering::Equal
+        } else {
+            self.0.total_cmp(&other.0)
+        }
+    }
+
+    /// return the smallest increment distinguishable at this magnitude
+    ///
+    /// This is the gap between the inner `f64` and the next representable `f64`
+    /// away from zero. At current epoch values this is a few hundred nanoseconds,
+    /// so `Seconds` can not represent nanosecond precision timestamps. Gaps too
+    /// large for a `Duration`, including the gap at non-finite values, saturate
+    /// at `Duration::MAX`
+    #[must_use]
+    pub fn resolution_at(&self) -> Duration {
+        let magnitude = self.0.abs();
+        let next = f64::from_bits(magnitude.to_bits() + 1);
+        Duration::try_from_secs_f64(next - magnitude).unwrap_or(Duration::MAX)
+    }
+
+    /// add a signed whole number of seconds, returning `None` if the result is not
+    /// finite or would precede the epoch
+    ///
+    /// This saves choosing between `+` and `-` based on the sign of an offset, and
+    /// follows [`Seconds::checked_sub`](struct.Seconds.html#method.checked_sub) in
+    /// rejecting results before the epoch
+    #[must_use]
+    pub fn try_add_signed(
+        self,
+        secs: i64,
+    ) -> Option<Self> {
+        let sum = self.0 + secs as f64;
+        if sum.is_finite() && sum >= 0.0 {
+            Some(Seconds(sum))
+        } else {
+            None
+        }
+    }
+
+    /// format these seconds with an explicit `s` unit suffix, e.g. `1545136342.711932s`
+    ///
+    /// The `Display` impl omits the suffix
+    #[must_use]
+    pub fn to_string_with_unit(&self) -> String {
+        format!("{}s", self)
+    }
+
+    /// format these seconds as the shortest decimal string which parses back to the
+    /// exact same value, such as `1.5`, never using exponential notation
+    ///
+    /// Unlike [`SecondsPrecision`](struct.SecondsPrecision.html) or
+    /// [`Seconds::fmt_duration_since`](struct.Seconds.html#method.fmt_duration_since)
+    /// there are no trailing zeros and no precision is lost
+    #[must_use]
+    pub fn to_compact_string(&self) -> String {
+        // f64's Display is already the shortest round trippable form and writes very
+        // large or small values out in full rather than in exponential notation
+        format!("{}", self.0)
+    }
+
+    /// return the number of whole nanoseconds since the epoch, rounded to the nearest
+    /// nanosecond
+    ///
+    /// The integer is only as exact as the `f64` it came from. At current epoch
+    /// values that is a few hundred nanoseconds, see
+    /// [`Seconds::resolution_at`](struct.Seconds.html#method.resolution_at). `NaN`
+    /// converts to `0`. Infinities, and values beyond the range of an `i128` of
+    /// nanoseconds, about 1.7e29 seconds, saturate
+    #[must_use]
+    pub fn nanos_since_epoch(&self) -> i128 {
+        let secs = self.0.floor();
+        // rounding the fraction may carry into the next whole second, which the
+        // addition takes care of
+        let nanos = ((self.0 - secs) * 1.0e9).round() as i128;
+        (secs as i128)
+            .saturating_mul(1_000_000_000)
+            .saturating_add(nanos)
+    }
+
+    /// create seconds from a whole number of nanoseconds since the epoch
+    pub fn from_nanos_i128(nanos: i128) -> Self {
+        let secs = nanos.div_euclid(1_000_000_000);
+        let nanos = nanos.rem_euclid(1_000_000_000);
+        Seconds(secs as f64 + nanos as f64 / 1.0e9)
+    }
+
+    /// create seconds from a whole number of nanoseconds since the epoch, returning
+    /// `None` if the `f64` result would not convert back to exactly `nanos`
+    ///
+    /// See [`Seconds::nanos_since_epoch`](struct.Seconds.html#method.nanos_since_epoch)
+    /// for the conversion back
+    pub fn checked_from_nanos(nanos: u128) -> Option<Self> {
+        let nanos = i128::try_from(nanos).ok()?;
+        let seconds = Seconds::from_nanos_i128(nanos);
+        if seconds.nanos_since_epoch() == nanos {
+            Some(seconds)
+        } else {
+            None
+        }
+    }
+
+    /// return these seconds if they are finite, otherwise `fallback`
+    ///
+    /// This keeps `NaN` and infinities produced by upstream arithmetic from
+    /// propagating past a trust boundary
+    pub fn clamp_to_valid(
+        self,
+        fallback: Seconds,
+    ) -> Self {
+        if self.is_finite() {
+            self
+        } else {
+            fallback
+        }
+    }
+
+    /// replace non-finite seconds with `fallback` and collapse negative zero into
+    /// positive zero
+    ///
+    /// This combines [`Seconds::clamp_to_valid`](struct.Seconds.html#method.clamp_to_valid)
+    /// and [`Seconds::normalize_zero`](struct.Seconds.html#method.normalize_zero) and is
+    /// the recommended way to sanitize untrusted seconds before using them as a map key
+    pub fn normalize(
+        self,
+        fallback: Seconds,
+    ) -> Self {
+        self.clamp_to_valid(fallback).normalize_zero()
+    }
+
+    /// return an integer key whose natural ordering matches `f64::total_cmp` on the
+    /// inner value
+    ///
+    /// Flipping every bit of negative values, and only the sign bit of positive
+    /// ones, turns the IEEE 754 representation into an unsigned integer which sorts
+    /// the same way, enabling radix sorts and other non-comparison sorts
+    #[must_use]
+    pub fn ord_key(&self) -> u64 {
+        const SIGN: u64 = 1 << 63;
+        let bits = self.0.to_bits();
+        if bits & SIGN == SIGN {
+            !bits
+        } else {
+            bits ^ SIGN
+        }
+    }
+
+    /// recover seconds from a key produced by [`Seconds::ord_key`](struct.Seconds.html#method.ord_key)
+    pub fn from_ord_key(key: u64) -> Self {
+        const SIGN: u64 = 1 << 63;
+        let bits = if key & SIGN == SIGN { key ^ SIGN } else { !key };
+        Seconds(f64::from_bits(bits))
+    }
+
+    /// encode as 8 bytes whose lexicographic order matches `f64::total_cmp` on the
+    /// inner value, for use as sortable key prefixes in ordered key-value stores
+    ///
+    /// This is the big-endian encoding of [`Seconds::ord_key`](struct.Seconds.html#method.ord_key)
+    #[must_use]
+    pub fn to_order_preserving_bytes(&self) -> [u8; 8] {
+        self.ord_key().to_be_bytes()
+    }
+
+    /// decode seconds from bytes produced by
+    /// [`Seconds::to_order_preserving_bytes`](struct.Seconds.html#method.to_order_preserving_bytes)
+    pub fn from_order_preserving_bytes(bytes: [u8; 8]) -> Self {
+        Seconds::from_ord_key(u64::from_be_bytes(bytes))
+    }
+
+    /// return true if `self` and `other` are at most `max_ulps` representable `f64`
+    /// values apart
+    ///
+    /// Unlike a fixed tolerance this scales with magnitude, as the gap between
+    /// adjacent values grows with their size. Positive and negative zero are equal,
+    /// values either side of zero are compared across it, and `NaN` is never equal
+    #[must_use]
+    pub fn approx_eq_ulps(
+        &self,
+        other: Seconds,
+        max_ulps: u32,
+    ) -> bool {
+        if self.is_nan() || other.is_nan() {
+            return false;
+        }
+        // lay every value out on a signed integer line where adjacent floats are
+        // adjacent integers and both zeros are 0
+        let steps = |secs: f64| {
+            let magnitude = (secs.to_bits() & !(1 << 63)) as i64;
+            if secs.is_sign_negative() {
+                -magnitude
+            } else {
+                magnitude
+            }
+        };
+        steps(self.0).abs_diff(steps(other.0)) <= u64::from(max_ulps)
+    }
+
+    /// convert to a duration since the epoch, returning `None` for negative or
+    /// non-finite seconds which a `Duration` can not represent
+    #[must_use]
+    pub fn checked_into_duration(&self) -> Option<Duration> {
+        Duration::try_from_secs_f64(self.0).ok()
+    }
+
+    /// divide `[from, to)` into `parts` contiguous, equally sized windows
+    ///
+    /// Each window ends exactly where the next begins and the last ends exactly at
+    /// `to`. Zero `parts` or an empty range, where `from >= to`, yields no windows
+    #[must_use]
+    pub fn split_range(
+        from: Seconds,
+        to: Seconds,
+        parts: usize,
+    ) -> Vec<(Seconds, Seconds)> {
+        if parts == 0 || from.0 >= to.0 {
+            return Vec::new();
+        }
+        let span = to.0 - from.0;
+        let boundary = |i: usize| {
+            if i == parts {
+                to
+            } else {
+                Seconds(from.0 + span * i as f64 / parts as f64)
+            }
+        };
+        (0..parts).map(|i| (boundary(i), boundary(i + 1))).collect()
+    }
+
+    /// find the earliest and latest of some timestamps in a single pass, or `None`
+    /// if there are none
+    ///
+    /// Values are compared with `f64::total_cmp`, so the result is well defined even
+    /// when some of the values are `NaN`
+    #[must_use]
+    pub fn min_max<I>(iter: I) -> Option<(Seconds, Seconds)>
+    where
+        I: IntoIterator<Item = Seconds>,
+    {
+        iter.into_iter().fold(None, |acc, secs| match acc {
+            None => Some((secs, secs)),
+            Some((min, max)) => Some((
+                if secs.0.total_cmp(&min.0) == Ordering::Less {
+                    secs
+                } else {
+                    min
+                },
+                if secs.0.total_cmp(&max.0) == Ordering::Greater {
+                    secs
+                } else {
+                    max
+                },
+            )),
+        })
+    }
+
+    /// replace the fractional part of these seconds with `nanos` nanoseconds
+    ///
+    /// Whole seconds are floored first, matching
+    /// [`Seconds::to_timestamp_parts`](struct.Seconds.html#method.to_timestamp_parts)
+    ///
+    /// # Panics
+    ///
+    /// Panics if `nanos` is not less than one second's worth of nanoseconds
+    pub fn with_subsec_nanos(
+        self,
+        nanos: u32,
+    ) -> Self {
+        assert!(
+            nanos < 1_000_000_000,
+            "subsecond nanos {} must be less than 1_000_000_000",
+            nanos
+        );
+        Seconds(self.0.floor() + f64::from(nanos) / 1.0e9)
+    }
+
+    /// extract seconds from a JSON number or a string containing a number
+    #[cfg(feature = "json")]
+    pub fn from_json_value(value: &serde_json::Value) -> Result<Self, ParseError> {
+        match value {
+            serde_json::Value::Number(number) => number.as_f64().map(Seconds),
+            serde_json::Value::String(s) => s.parse().ok(),
+            _ => None,
+        }
+        .ok_or_else(|| ParseError::new(&value.to_string(), "json seconds"))
+    }
+
+    /// return the number of whole 24 hour days from `self` until `other`
+    ///
+    /// Partial days are truncated toward zero and the result is negative when
+    /// `other` is earlier than `self`. Days are a fixed 86400 seconds, not calendar
+    /// days, so there is no accounting for daylight saving time
+    #[must_use]
+    pub fn days_between(
+        &self,
+        other: Seconds,
+    ) -> i64 {
+        ((other.0 - self.0) / 86_400.0).trunc() as i64
+    }
+
+    /// return the number of whole hours from `self` until `other`
+    ///
+    /// Partial hours are truncated toward zero and the result is negative when
+    /// `other` is earlier than `self`
+    #[must_use]
+    pub fn hours_between(
+        &self,
+        other: Seconds,
+    ) -> i64 {
+        ((other.0 - self.0) / 3_600.0).trunc() as i64
+    }
+
+    /// return the fractional number of 24 hour days from `self` until `other`,
+    /// negative when `other` is earlier than `self`
+    #[must_use]
+    pub fn diff_days_f64(
+        &self,
+        other: Seconds,
+    ) -> f64 {
+        (other.0 - self.0) / 86_400.0
+    }
+
+    /// return the fractional number of hours from `self` until `other`, negative
+    /// when `other` is earlier than `self`
+    #[must_use]
+    pub fn diff_hours_f64(
+        &self,
+        other: Seconds,
+    ) -> f64 {
+        (other.0 - self.0) / 3_600.0
+    }
+
+    /// return the fractional number of minutes from `self` until `other`, negative
+    /// when `other` is earlier than `self`
+    #[must_use]
+    pub fn diff_minutes_f64(
+        &self,
+        other: Seconds,
+    ) -> f64 {
+        (other.0 - self.0) / 60.0
+    }
+
+    /// add a duration, returning the sum along with whether it overflowed
+    ///
+    /// The sum overflows when it lies past
+    /// [`Seconds::MAX`](struct.Seconds.html#associatedconstant.MAX), the same bound
+    /// [`Seconds::saturating_add`](struct.Seconds.html#method.saturating_add) clamps
+    /// to, including sums which are infinite. Unlike `saturating_add` the sum itself
+    /// is returned unclamped. `NaN` never overflows
+    pub fn overflowing_add(
+        self,
+        rhs: Duration,
+    ) -> (Seconds, bool) {
+        let sum = self.0 + rhs.as_secs_f64();
+        (Seconds(sum), sum > Seconds::MAX.0)
+    }
+
+    /// estimate the monotonic `Instant` corresponding to these seconds
+    ///
+    /// This pairs a read of the wall clock with a read of the monotonic clock and
+    /// applies the offset between `self` and now. It is only an approximation,
+    /// the two clock reads are not simultaneous and any later wall clock
+    /// adjustment, such as an NTP step, will not be reflected in the `Instant`.
+    /// Returns `None` when the offset can not be represented by an `Instant`
+    #[must_use]
+    pub fn to_instant_estimate(&self) -> Option<Instant> {
+        let (wall, instant) = (Seconds::now(), Instant::now());
+        let offset = self.0 - wall.0;
+        let magnitude = Duration::try_from_secs_f64(offset.abs()).ok()?;
+        if offset >= 0.0 {
+            instant.checked_add(magnitude)
+        } else {
+            instant.checked_sub(magnitude)
+        }
+    }
+
+    /// add a duration, saturating at [`Seconds::MAX`](struct.Seconds.html#associatedconstant.MAX)
+    pub fn saturating_add(
+        self,
+        rhs: Duration,
+    ) -> Self {
+        let sum = self.0 + rhs.as_secs_f64();
+        if sum > Seconds::MAX.0 {
+            Seconds::MAX
+        } else {
+            Seconds(sum)
+        }
+    }
+
+    /// add a signed, fractional number of seconds, saturating at
+    /// [`Seconds::MIN`](struct.Seconds.html#associatedconstant.MIN) and
+    /// [`Seconds::MAX`](struct.Seconds.html#associatedconstant.MAX)
+    ///
+    /// Infinite results clamp to the nearest bound and a `NaN` delta leaves these
+    /// seconds unchanged, so messy floating point corrections can't poison a value
+    pub fn saturating_add_signed_secs(
+        self,
+        delta: f64,
+    ) -> Self {
+        if delta.is_nan() {
+            return self;
+        }
+        Seconds((self.0 + delta).clamp(Seconds::MIN.0, Seconds::MAX.0))
+    }
+
+    /// return the duration elapsed since `earlier`, or `None` if `earlier` is
+    /// actually later than `self`
+    ///
+    /// This mirrors `SystemTime::checked_duration_since`
+    #[must_use]
+    pub fn checked_duration_since(
+        &self,
+        earlier: Seconds,
+    ) -> Option<Duration> {
+        Duration::try_from_secs_f64(self.0 - earlier.0).ok()
+    }
+
+    /// return true if these seconds as a number of milliseconds can be exactly
+    /// represented by a JavaScript number
+    ///
+    /// JavaScript integers are only exact up to `Number.MAX_SAFE_INTEGER`, 2^53 - 1.
+    /// Epoch milliseconds stay well within that until the year 287396, but finer
+    /// units such as nanoseconds do not
+    #[must_use]
+    pub fn is_js_safe_millis(&self) -> bool {
+        (self.0 * 1.0e3).abs() <= Seconds::MAX.0
+    }
+
+    /// return true if `point` falls within the half-open span `[start, end)`
+    ///
+    /// The start is included and the end is excluded, so adjacent spans sharing a
+    /// boundary never both contain it
+    #[must_use]
+    pub fn span_contains(
+        point: Seconds,
+        start: Seconds,
+        end: Seconds,
+    ) -> bool {
+        start.0 <= point.0 && point.0 < end.0
+    }
+
+    /// return true if the half-open `(start, end)` spans `a` and `b` share any point
+    ///
+    /// Spans which only touch, where one ends exactly where the other starts, do
+    /// not overlap
+    #[must_use]
+    pub fn spans_overlap(
+        a: (Seconds, Seconds),
+        b: (Seconds, Seconds),
+    ) -> bool {
+        a.0 .0 < b.1 .0 && b.0 .0 < a.1 .0
+    }
+
+    /// format the signed time elapsed since `start` with millisecond precision and
+    /// an `s` unit suffix, e.g. `1.234s`
+    ///
+    /// A `start` later than `self` renders as a negative elapsed time
+    #[must_use]
+    pub fn fmt_duration_since(
+        &self,
+        start: Seconds,
+    ) -> String {
+        format!("{:.3}s", self.0 - start.0)
+    }
+
+    /// return true if these seconds are no older than `max_age`
+    ///
+    /// Timestamps in the future are treated as having an age of zero
+    #[must_use]
+    pub fn age_within(
+        &self,
+        max_age: Duration,
+    ) -> bool {
+        self.age_within_with(&SystemClock, max_age)
+    }
+
+    /// same as [`Seconds::age_within`](struct.Seconds.html#method.age_within) but
+    /// reading the current time from the provided clock
+    #[must_use]
+    pub fn age_within_with<C>(
+        &self,
+        clock: &C,
+        max_age: Duration,
+    ) -> bool
+    where
+        C: Clock + ?Sized,
+    {
+        // future timestamps have a negative age, which is always within max_age
+        clock.now().0 - self.0 <= max_age.as_secs_f64()
+    }
+
+    /// return the magnitude of the gap between `self` and `other` along with whether
+    /// `self` is the later of the two
+    ///
+    /// Unlike `Duration` subtraction this never panics. Gaps too large for a
+    /// `Duration` saturate at `Duration::MAX` and a `NaN` gap is reported as zero
+    #[must_use]
+    pub fn as_duration_since(
+        &self,
+        other: Seconds,
+    ) -> (Duration, bool) {
+        let diff = self.0 - other.0;
+        let magnitude = Duration::try_from_secs_f64(diff.abs()).unwrap_or(if diff.is_nan() {
+            Duration::ZERO
+        } else {
+            Duration::MAX
+        });
+        (magnitude, diff > 0.0)
+    }
+
+    /// return the index of the UTC day these seconds fall on, counting days since
+    /// the epoch
+    ///
+    /// Pre-epoch seconds floor toward the past, so the second before the epoch is
+    /// on day `-1`. Non-finite seconds saturate, with `NaN` on day `0`
+    #[must_use]
+    pub fn epoch_day(&self) -> i64 {
+        self.0.div_euclid(86_400.0) as i64
+    }
+
+    /// return the number of seconds since midnight UTC, always within `[0, 86400)`
+    ///
+    /// Pre-epoch seconds wrap using the Euclidean remainder, so the second before the
+    /// epoch is `86399` rather than `-1`
+    #[must_use]
+    pub fn seconds_of_day_utc(&self) -> f64 {
+        let secs = self.0.rem_euclid(86_400.0);
+        // tiny negative values round up to a whole day
+        if secs == 86_400.0 {
+            0.0
+        } else {
+            secs
+        }
+    }
+
+    /// convert to an integer count of `10^-scale` second units since the epoch, such
+    /// as `scale` 6 for microseconds, resolving fractional units with `mode`
+    ///
+    /// The scale factor is built by exact multiplication rather than `powi` so the
+    /// same seconds always produce the same integer on every platform. Results beyond
+    /// the range of an `i64` saturate at `i64::MIN` or `i64::MAX`, and `NaN` is `0`
+    #[must_use]
+    pub fn to_fixed_point(
+        &self,
+        scale: u32,
+        mode: RoundingMode,
+    ) -> i64 {
+        mode.apply(self.0 * fixed_point_factor(scale)) as i64
+    }
+
+    /// create seconds from each of a slice of millisecond timestamps, such as a
+    /// column decoded from Arrow or Parquet
+    ///
+    /// Each value converts exactly as `Seconds::from_fixed_point(millis, 3)` does.
+    /// Whole milliseconds survive the conversion only while the seconds since the
+    /// epoch have sub-millisecond `f64` precision, which holds until well past the
+    /// year 200000
+    #[must_use]
+    pub fn from_millis_slice(millis: &[i64]) -> Vec<Seconds> {
+        Self::from_millis_iter(millis.iter().copied()).collect()
+    }
+
+    /// lazily create seconds from an iterator of millisecond timestamps, see
+    /// [`Seconds::from_millis_slice`](struct.Seconds.html#method.from_millis_slice)
+    pub fn from_millis_iter<I>(millis: I) -> impl Iterator<Item = Seconds>
+    where
+        I: IntoIterator<Item = i64>,
+    {
+        millis
+            .into_iter()
+            .map(|millis| Seconds::from_fixed_point(millis, 3))
+    }
+
+    /// return the inner `f64` rounded to `digits` decimal places, with half way cases
+    /// rounding away from zero, to avoid noisy trailing digits downstream
+    ///
+    /// Values too large to gain anything from rounding are returned unchanged
+    #[must_use]
+    pub fn as_secs_f64_rounded(
+        &self,
+        digits: u32,
+    ) -> f64 {
+        let scale = fixed_point_factor(digits);
+        let rounded = (self.0 * scale).round() / scale;
+        // beyond f64's precision scaling may overflow
+        if rounded.is_finite() {
+            rounded
+        } else {
+            self.0
+        }
+    }
+
+    /// return true if converting to fixed point at `scale`, rounding to the nearest
+    /// unit, and back reproduces exactly the same bits
+    ///
+    /// This verifies no precision is lost by storing these seconds with
+    /// [`Seconds::to_fixed_point`](struct.Seconds.html#method.to_fixed_point)
+    #[must_use]
+    pub fn round_trips_at_scale(
+        &self,
+        scale: u32,
+    ) -> bool {
+        let fixed = self.to_fixed_point(scale, RoundingMode::Nearest);
+        Seconds::from_fixed_point(fixed, scale).0.to_bits() == self.0.to_bits()
+    }
+
+    /// create seconds from an integer count of `10^-scale` second units since the
+    /// epoch, the inverse of [`Seconds::to_fixed_point`](struct.Seconds.html#method.to_fixed_point)
+    pub fn from_fixed_point(
+        value: i64,
+        scale: u32,
+    ) -> Self {
+        match 10i64.checked_pow(scale) {
+            // whole seconds and the remainder are converted separately to avoid
+            // losing precision on large values
+            Some(units) => {
+                let secs = value.div_euclid(units) as f64;
+                Seconds(secs + value.rem_euclid(units) as f64 / units as f64)
+            }
+            None => Seconds(value as f64 / fixed_point_factor(scale)),
+        }
+    }
+
+    /// return the floored base 2 logarithm of the age of these seconds at `now`,
+    /// bucketing ages on a log scale of 1s, 2s, 4s, 8s and so on
+    ///
+    /// Ages under two seconds, including future timestamps, fall into bucket `0`
+    #[must_use]
+    pub fn log2_age_bucket(
+        &self,
+        now: Seconds,
+    ) -> u32 {
+        let age = now.0 - self.0;
+        if age.is_nan() || age < 1.0 {
+            0
+        } else {
+            age.log2().floor() as u32
+        }
+    }
+
+    /// return the number of milliseconds since the epoch, rounded to the nearest
+    /// millisecond, for export to systems expecting unsigned integer milliseconds
+    ///
+    /// Pre-epoch seconds and `NaN` clamp to `0` and values beyond the range of a
+    /// `u64` clamp to `u64::MAX`, rather than wrapping
+    #[must_use]
+    pub fn saturating_millis_u64(&self) -> u64 {
+        // float to integer casts saturate
+        (self.0 * 1.0e3).round() as u64
+    }
+
+    /// break these seconds, as an interval, down into weeks, days, hours, minutes,
+    /// seconds and nanoseconds for custom human readable formatting
+    ///
+    /// Negative intervals are broken down by their magnitude. As with
+    /// [`Seconds::as_duration_since`](struct.Seconds.html#method.as_duration_since),
+    /// intervals too large for a `Duration` saturate and `NaN` is zero
+    #[must_use]
+    pub fn relative_parts(&self) -> RelativeParts {
+        let (magnitude, _) = self.as_duration_since(Seconds::EPOCH);
+        let secs = magnitude.as_secs();
+        RelativeParts {
+            weeks: secs / 604_800,
+            days: (secs % 604_800 / 86_400) as u8,
+            hours: (secs % 86_400 / 3_600) as u8,
+            minutes: (secs % 3_600 / 60) as u8,
+            seconds: (secs % 60) as u8,
+            subsec_nanos: magnitude.subsec_nanos(),
+        }
+    }
+
+    /// return the time elapsed since these seconds and reset them to now, reading
+    /// the clock once for both
+    ///
+    /// This suits a "last run" field on a periodic task. The elapsed time saturates
+    /// at zero when these seconds are in the future
+    pub fn elapsed_and_reset(&mut self) -> Duration {
+        self.elapsed_and_reset_with(&SystemClock)
+    }
+
+    /// same as [`Seconds::elapsed_and_reset`](struct.Seconds.html#method.elapsed_and_reset)
+    /// but reading the current time from the provided clock
+    pub fn elapsed_and_reset_with<C>(
+        &mut self,
+        clock: &C,
+    ) -> Duration
+    where
+        C: Clock + ?Sized,
+    {
+        let now = clock.now();
+        let elapsed = match now.as_duration_since(*self) {
+            (elapsed, true) => elapsed,
+            _ => Duration::ZERO,
+        };
+        *self = now;
+        elapsed
+    }
+
+    /// return the fractional position of `query` within an ascending series of
+    /// timestamps, for linearly interpolating an associated series of values
+    ///
+    /// A query between two samples lands proportionally between their indexes while
+    /// an exact match returns the index of the first sample equal to it. Queries
+    /// outside of the series, or `NaN`, return `None`
+    #[must_use]
+    pub fn interpolate_index(
+        query: Seconds,
+        sorted: &[Seconds],
+    ) -> Option<f64> {
+        let (first, last) = (sorted.first()?, sorted.last()?);
+        if !(first.0..=last.0).contains(&query.0) {
+            return None;
+        }
+        let index = sorted.partition_point(|secs| secs.0 < query.0);
+        if sorted[index].0 == query.0 {
+            return Some(index as f64);
+        }
+        let (lo, hi) = (sorted[index - 1].0, sorted[index].0);
+        Some((index - 1) as f64 + (query.0 - lo) / (hi - lo))
+    }
+
+    /// return the timestamp at fraction `p` of the way through an ascending series,
+    /// such as `0.5` for the median, interpolating linearly between the two nearest
+    /// samples
+    ///
+    /// `p` is clamped to `[0, 1]`, so `0.0` and `1.0` return the first and last
+    /// samples. An empty series returns `None`
+    #[must_use]
+    pub fn percentile(
+        sorted: &[Seconds],
+        p: f64,
+    ) -> Option<Seconds> {
+        let last = sorted.len().checked_sub(1)?;
+        let rank = p.clamp(0.0, 1.0) * last as f64;
+        let (lo, hi) = (sorted[rank.floor() as usize], sorted[rank.ceil() as usize]);
+        Some(Seconds(lo.0 + (hi.0 - lo.0) * rank.fract()))
+    }
+
+    /// estimate the tick period of a periodic source from a series of its timestamps,
+    /// as the median gap between consecutive samples
+    ///
+    /// The median is robust to the odd late or dropped sample. Gaps are measured by
+    /// their magnitude, so a descending series works too. Fewer than two samples, or
+    /// samples which aren't finite, return `None`
+    #[must_use]
+    pub fn estimate_period(samples: &[Seconds]) -> Option<Duration> {
+        if !samples.iter().all(Seconds::is_finite) {
+            return None;
+        }
+        let mut gaps: Vec<f64> = samples
+            .windows(2)
+            .map(|pair| (pair[1].0 - pair[0].0).abs())
+            .collect();
+        gaps.sort_by(f64::total_cmp);
+        let mid = gaps.len() / 2;
+        let median = match gaps.len() {
+            0 => return None,
+            len if len % 2 == 0 => (gaps[mid - 1] + gaps[mid]) / 2.0,
+            _ => gaps[mid],
+        };
+        Duration::try_from_secs_f64(median).ok()
+    }
+
+    /// return the rate of `count` events over the interval between `from` and `to`,
+    /// in events per second
+    ///
+    /// The rate is never negative, the interval's length is used regardless of which
+    /// end comes first. A zero length interval has a rate of `f64::INFINITY`, or
+    /// `0.0` when there were no events
+    #[must_use]
+    pub fn rate(
+        count: u64,
+        from: Seconds,
+        to: Seconds,
+    ) -> f64 {
+        if count == 0 {
+            return 0.0;
+        }
+        count as f64 / (to.0 - from.0).abs()
+    }
+
+    /// return `n` timestamps evenly spread across `[from, to)`, the `i`th being
+    /// `from + i * (to - from) / n`
+    ///
+    /// Like [`Seconds::windows`](struct.Seconds.html#method.windows), an empty or
+    /// reversed range, where `from >= to`, yields no timestamps
+    #[must_use]
+    pub fn spread(
+        from: Seconds,
+        to: Seconds,
+        n: usize,
+    ) -> Vec<Seconds> {
+        if from.0 >= to.0 || from.0.is_nan() || to.0.is_nan() {
+            return Vec::new();
+        }
+        let step = (to.0 - from.0) / n as f64;
+        (0..n).map(|i| Seconds(from.0 + i as f64 * step)).collect()
+    }
+
+    /// transformation is kept private as we can make no guarantees
+    /// about whether a provided duration is anchored in any way to
+    /// unix time
+    fn from_duration(dur: Duration) -> Self {
+        Seconds(dur.as_secs() as f64 + (f64::from(dur.subsec_nanos()) / 1.0e9))
+    }
+}
+
+/// join whole seconds and a count of `1 / per_sec` second units, carrying whole
+/// seconds out of `fraction` in `f64` so that extreme parts can't overflow
+fn join_parts(
+    whole: i64,
+    fraction: i64,
+    per_sec: i64,
+) -> Seconds {
+    let carry = fraction.div_euclid(per_sec);
+    let fraction = fraction.rem_euclid(per_sec);
+    Seconds((whole as f64 + carry as f64) + fraction as f64 / per_sec as f64)
+}
+
+/// true when `value` lies within the range of an `i64`, so that casting it to one
+/// doesn't saturate. `NaN` never fits
+#[cfg(any(feature = "serde", feature = "date"))]
+fn fits_i64(value: f64) -> bool {
+    // i64::MAX as f64 rounds up to 2^63, which is itself out of range
+    value >= i64::MIN as f64 && value < i64::MAX as f64
+}
+
+/// `10^scale` as an `f64`, exact up to a scale of 22
+fn fixed_point_factor(scale: u32) -> f64 {
+    // anything beyond f64's maximum exponent is infinite anyway
+    (0..scale.min(310)).fold(1.0, |factor, _| factor * 10.0)
+}
+
+/// Parses decimal seconds, like the output of `Display`. Non-finite values such as
+/// `NaN` or `inf` are rejected
+impl FromStr for Seconds {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.parse::<f64>() {
+            Ok(secs) if secs.is_finite() => Ok(Seconds(secs)),
+            _ => Err(ParseError::new(s, "seconds")),
+        }
+    }
+}
+
+/// Parses decimal seconds, exactly as `FromStr` does
+impl TryFrom<&str> for Seconds {
+    type Error = ParseError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+/// Hashes the bit pattern of the inner value, with negative zero normalized so
+/// that values which compare equal also hash equal
+impl Hash for Seconds {
+    fn hash<H: Hasher>(
+        &self,
+        state: &mut H,
+    ) {
+        self.normalize_zero().0.to_bits().hash(state)
+    }
+}
+
+impl Default for Seconds {
+    fn default() -> Self {
+        Seconds::now()
+    }
+}
+
+impl From<Seconds> for f64 {
+    fn from(seconds: Seconds) -> Self {
+        let Seconds(secs) = seconds;
+        secs
+    }
+}
+
+/// Similar to `date -v+1S +%s`
+impl Add<Duration> for Seconds {
+    type Output = Seconds;
+    fn add(
+        self,
+        rhs: Duration,
+    ) -> Self::Output {
+        let lhs: Duration = self.into();
+        Seconds::from_duration(lhs + rhs)
+    }
+}
+
+/// Similar to `date -v+1S +%s`, with the operands commuted
+impl Add<Seconds> for Duration {
+    type Output = Seconds;
+    fn add(
+        self,
+        rhs: Seconds,
+    ) -> Self::Output {
+        rhs + self
+    }
+}
+
+/// Similar to `date -v-1S +%s`
+///
+/// Subtracting a duration larger than the time since the epoch saturates at
+/// [`Seconds::EPOCH`](struct.Seconds.html#associatedconstant.EPOCH) rather than
+/// panicking. Use [`Seconds::checked_sub`](struct.Seconds.html#method.checked_sub)
+/// to detect this case. Seconds already before the epoch have no epoch to saturate
+/// at, so the duration is subtracted from them as is, and the result never moves
+/// forward in time
+impl Sub<Duration> for Seconds {
+    type Output = Seconds;
+    fn sub(
+        self,
+        rhs: Duration,
+    ) -> Self::Output {
+        let (secs, _) = self.sub_saturating_reported(rhs);
+        secs
+    }
+}
+
+/// The offset into the current `rhs` sized window, counting windows from the epoch
+///
+/// The remainder is Euclidean, so seconds before the epoch also yield a
+/// non-negative offset
+///
+/// # Panics
+///
+/// Panics if `rhs` is zero, just like integer remainder by zero, or if these
+/// seconds are not finite
+impl Rem<Duration> for Seconds {
+    type Output = Duration;
+    fn rem(
+        self,
+        rhs: Duration,
+    ) -> Self::Output {
+        assert!(
+            rhs != Duration::from_secs(0),
+            "remainder window must be non-zero"
+        );
+        Duration::from_secs_f64(self.0.rem_euclid(rhs.as_secs_f64()))
+    }
+}
+
+impl From<Seconds> for Duration {
+    fn from(seconds: Seconds) -> Self {
+        let Seconds(secs) = seconds;
+        Duration::new(secs.trunc() as u64, (secs.fract() * 1.0e9) as u32)
+    }
+}
+
+/// Compares against a `Duration` interpreted as time elapsed since the unix epoch,
+/// the same anchoring used when converting `Seconds` into a `Duration`. Comparing
+/// against a relative duration, like a timeout, is almost certainly a mistake
+impl PartialEq<Duration> for Seconds {
+    fn eq(
+        &self,
+        other: &Duration,
+    ) -> bool {
+        self.0 == other.as_secs_f64()
+    }
+}
+
+/// Orders against a `Duration` interpreted as time elapsed since the unix epoch
+impl PartialOrd<Duration> for Seconds {
+    fn partial_cmp(
+        &self,
+        other: &Duration,
+    ) -> Option<Ordering> {
+        self.0.partial_cmp(&other.as_secs_f64())
+    }
+}
+
+#[cfg(all(feature = "coarse-clock", target_os = "linux"))]
+mod coarse {
+    use super::Seconds;
+
+    pub fn now() -> Option<Seconds> {
+        let mut ts = libc::timespec {
+            tv_sec: 0,
+            tv_nsec: 0,
+        };
+        // safety: ts is a valid, writable timespec for the duration of the call
+        if unsafe { libc::clock_gettime(libc::CLOCK_REALTIME_COARSE, &mut ts) } != 0 {
+            return None;
+        }
+        Some(Seconds(ts.tv_sec as f64 + ts.tv_nsec as f64 / 1.0e9))
+    }
+}
+
+#[cfg(feature = "test-clock")]
+mod frozen {
+    use super::Seconds;
+    use std::cell::Cell;
+
+    thread_local! {
+        static FROZEN: Cell<Option<Seconds>> = const { Cell::new(None) };
+    }
+
+    pub fn get() -> Option<Seconds> {
+        FROZEN.with(Cell::get)
+    }
+
+    pub fn set(now: Option<Seconds>) {
+        FROZEN.with(|frozen| frozen.set(now))
+    }
+}
+
+/// Serializes seconds rounded to a number of fractional digits chosen at runtime
+///
+/// ```rust
+/// # fn main() -> Result<(), serde_json::Error> {
+/// let seconds = "1545136342.711932".parse().expect("invalid seconds");
+/// let json = serde_json::to_string(&unisecs::SecondsPrecision { seconds, digits: 3 })?;
+/// assert_eq!(json, "1545136342.712");
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "serde")]
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct SecondsPrecision {
+    /// the seconds to serialize
+    pub seconds: Seconds,
+    /// the number of fractional digits to round to
+    pub digits: u8,
+}
+
+#[cfg(feature = "serde")]
+impl ser::Serialize for SecondsPrecision {
+    fn serialize<S>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let scale = fixed_point_factor(u32::from(self.digits));
+        let scaled = (self.seconds.0 * scale).round() / scale;
+        // beyond f64's precision rounding is a no-op, and scaling may overflow
+        if scaled.is_finite() {
+            serializer.serialize_f64(scaled)
+        } else {
+            self.seconds.serialize(serializer)
+        }
+    }
+}
+
+/// The wire formats a [`SecondsWith`](struct.SecondsWith.html) can serialize as
+#[cfg(feature = "serde")]
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum SecondsFormat {
+    /// floating point seconds, the default `Seconds` serialization
+    FloatSecs,
+    /// integer seconds, truncated toward the past
+    IntSecs,
+    /// integer milliseconds, rounded to the nearest millisecond
+    IntMillis,
+    /// an RFC 3339 UTC string, see
+    /// [`Seconds::to_rfc3339`](struct.Seconds.html#method.to_rfc3339)
+    #[cfg(feature = "date")]
+    Rfc3339,
+}
+
+/// Serializes seconds in a wire format chosen at runtime
+///
+/// ```rust
+/// # fn main() -> Result<(), serde_json::Error> {
+/// use unisecs::{SecondsFormat, SecondsWith};
+/// let value = "1545136342.711932".parse().expect("invalid seconds");
+/// let json = serde_json::to_string(&SecondsWith { value, format: SecondsFormat::IntMillis })?;
+/// assert_eq!(json, "1545136342712");
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "serde")]
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct SecondsWith {
+    /// the seconds to serialize
+    pub value: Seconds,
+    /// the format to serialize them in
+    pub format: SecondsFormat,
+}
+
+#[cfg(feature = "serde")]
+impl ser::Serialize for SecondsWith {
+    fn serialize<S>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if !self.value.is_finite() {
+            return Err(ser::Error::custom("cannot serialize non-finite Seconds"));
+        }
+        match self.format {
+            SecondsFormat::FloatSecs => self.value.serialize(serializer),
+            SecondsFormat::IntSecs => serializer.serialize_i64(self.value.0.floor() as i64),
+            SecondsFormat::IntMillis => {
+                serializer.serialize_i64((self.value.0 * 1.0e3).round() as i64)
+            }
+            #[cfg(feature = "date")]
+            SecondsFormat::Rfc3339 => serializer.serialize_str(&self.value.to_rfc3339()),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+struct SecondsVisitor;
+
+#[cfg(feature = "serde")]
+impl<'de> de::Visitor<'de> for SecondsVisitor {
+    type Value = Seconds;
+
+    fn expecting(
+        &self,
+        formatter: &mut fmt::Formatter,
+    ) -> fmt::Result {
+        formatter.write_str("floating point seconds")
+    }
+    fn visit_f64<E>(
+        self,
+        value: f64,
+    ) -> Result<Seconds, E>
+    where
+        E: de::Error,
+    {
+        Ok(Seconds(value))
+    }
+    fn visit_bool<E>(
+        self,
+        value: bool,
+    ) -> Result<Seconds, E>
+    where
+        E: de::Error,
+    {
+        Err(E::invalid_type(de::Unexpected::Bool(value), &self))
+    }
+    fn visit_unit<E>(self) -> Result<Seconds, E>
+    where
+        E: de::Error,
+    {
+        Err(E::invalid_type(de::Unexpected::Unit, &self))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl ser::Serialize for Seconds {
+    fn serialize<S>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let Seconds(seconds) = self;
+        if !seconds.is_finite() {
+            return Err(ser::Error::custom("cannot serialize non-finite Seconds"));
+        }
+        serializer.serialize_f64(*seconds)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> de::Deserialize<'de> for Seconds {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        deserializer.deserialize_f64(SecondsVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        ClockSource, FixedClock, RelativeParts, RetentionBucket, RoundingMode, Seconds, Tolerance,
+    };
+    #[cfg(feature = "serde")]
+    use super::{SecondsFormat, SecondsPrecision, SecondsWith};
+    use std::{
+        cmp::Ordering,
+        collections::hash_map::DefaultHasher,
+        convert::TryFrom,
+        hash::{Hash, Hasher},
+        time::{Duration, Instant},
+    };
+
+    fn hash_of(secs: Seconds) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        secs.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn seconds_default() {
+        let (now, default) = (Seconds::default(), Seconds::now());
+        assert_eq!(now.trunc(), default.trunc());
+    }
+
+    #[test]
+    fn seconds_now_coarse() {
+        let (coarse, now) = (Seconds::now_coarse(), Seconds::now());
+        assert!((now.0 - coarse.0).abs() < 1.0);
+    }
+
+    #[test]
+    fn seconds_now_many() {
+        let batch = Seconds::now_many(3);
+        let now = Seconds::now();
+        assert_eq!(batch.len(), 3);
+        assert!(batch.iter().all(|secs| *secs == batch[0]));
+        assert!((now.0 - batch[0].0).abs() < 1.0);
+    }
+
+    #[test]
+    fn seconds_deref() {
+        let secs = Seconds(1_545_136_342.711_932);
+        let _f: f64 = secs.into();
+    }
+
+    #[test]
+    fn seconds_display() {
+        let secs = Seconds(1_545_136_342.711_932);
+        assert_eq!(format!("{}", secs), "1545136342.711932");
+    }
+
+    #[test]
+    fn seconds_duration_interop() {
+        let secs = Seconds(1_545_136_342.711_932);
+        let duration: Duration = secs.into();
+        assert_eq!(duration.as_secs(), 1_545_136_342);
+    }
+
+    #[test]
+    fn seconds_add_duration() {
+        let secs = Seconds(1_545_136_342.711_932);
+        assert_eq!(
+            secs + Duration::from_secs(1),
+            Seconds(1_545_136_343.711_932)
+        );
+    }
+
+    #[test]
+    fn seconds_sub_duration() {
+        let secs = Seconds(1_545_136_342.711_932);
+        assert_eq!(
+            secs - Duration::from_secs(1),
+            Seconds(1_545_136_341.711_932)
+        );
+    }
+
+    #[test]
+    fn duration_add_seconds() {
+        let secs = Seconds(1_545_136_342.711_932);
+        let dur = Duration::from_secs(1);
+        assert_eq!(dur + secs, secs + dur);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn seconds_serialize() {
+        assert_eq!(
+            serde_json::to_string(&Seconds(1_545_136_342.711_932)).expect("failed to serialize"),
+            "1545136342.711932"
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn seconds_serialize_non_finite() {
+        for secs in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            let err = serde_json::to_string(&Seconds(secs)).unwrap_err();
+            assert_eq!(err.to_string(), "cannot serialize non-finite Seconds");
+        }
+        assert_eq!(
+            serde_json::to_string(&SecondsPrecision {
+                seconds: Seconds(f64::NAN),
+                digits: 3
+            })
+            .unwrap_err()
+            .to_string(),
+            "cannot serialize non-finite Seconds"
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn seconds_deserialize_floats() {
+        assert_eq!(
+            serde_json::from_slice::<Seconds>(b"1545136342.711932").expect("failed to serialize"),
+            Seconds(1_545_136_342.711_932)
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn seconds_fails_to_deserialize() {
+        match serde_json::from_slice::<Seconds>(b"{\"foo\":\"bar\"}") {
+            Err(err) => assert_eq!(
+                format!("{}", err),
+                "invalid type: map, expected floating point seconds at line 1 column 0"
+            ),
+            Ok(other) => panic!("unexpected result {}", other),
+        }
+    }
+
+    #[test]
+    fn seconds_lerp() {
+        let (start, end) = (Seconds(0.0), Seconds(10.0));
+        assert_eq!(Seconds::lerp(start, end, 0.0), start);
+        assert_eq!(Seconds::lerp(start, end, 1.0), end);
+        assert_eq!(Seconds::lerp(start, end, 0.5), Seconds(5.0));
+        assert_eq!(Seconds::lerp(start, end, 2.0), end);
+    }
+
+    #[test]
+    fn seconds_progress() {
+        let (start, end) = (Seconds(0.0), Seconds(10.0));
+        assert_eq!(Seconds(5.0).progress(start, end), 0.5);
+        assert_eq!(Seconds(-5.0).progress(start, end), 0.0);
+        assert_eq!(Seconds(15.0).progress(start, end), 1.0);
+        assert_eq!(Seconds(5.0).progress(start, start), 1.0);
+    }
+
+    #[test]
+    fn seconds_to_timestamp_parts() {
+        assert_eq!(
+            Seconds(1_545_136_342.5).to_timestamp_parts(),
+            (1_545_136_342, 500_000_000)
+        );
+        assert_eq!(Seconds(-1.25).to_timestamp_parts(), (-2, 750_000_000));
+    }
+
+    #[test]
+    fn seconds_from_timestamp_parts() {
+        assert_eq!(
+            Seconds::from_timestamp_parts(1_545_136_342, 500_000_000),
+            Seconds(1_545_136_342.5)
+        );
+        assert_eq!(
+            Seconds::from_timestamp_parts(-2, 750_000_000),
+            Seconds(-1.25)
+        );
+        assert_eq!(
+            Seconds::from_timestamp_parts(1, 1_500_000_000),
+            Seconds(2.5)
+        );
+        assert_eq!(Seconds::from_timestamp_parts(1, -500_000_000), Seconds(0.5));
+        assert_eq!(
+            Seconds::from_timestamp_parts(i64::MAX, 1_500_000_000),
+            Seconds(i64::MAX as f64)
+        );
+        assert_eq!(
+            Seconds::from_timestamp_parts(i64::MIN, -1_500_000_000),
+            Seconds(i64::MIN as f64)
+        );
+    }
+
+    #[test]
+    fn seconds_float_inspection() {
+        let nan = Seconds(f64::NAN);
+        assert!(nan.is_nan() && !nan.is_finite() && !nan.is_infinite());
+        let inf = Seconds(f64::INFINITY);
+        assert!(inf.is_infinite() && !inf.is_finite() && !inf.is_nan());
+        let secs = Seconds(1_545_136_342.711_932);
+        assert!(secs.is_finite() && !secs.is_nan() && !secs.is_infinite());
+    }
+
+    #[test]
+    fn seconds_normalize_zero() {
+        let zero = Seconds(-0.0).normalize_zero();
+        assert!(zero.0.is_sign_positive());
+        assert_eq!(zero, Seconds(0.0));
+        assert_eq!(hash_of(zero), hash_of(Seconds(0.0)));
+        assert_eq!(hash_of(Seconds(-0.0)), hash_of(Seconds(0.0)));
+    }
+
+    #[test]
+    fn seconds_within_absolute() {
+        let tol = Tolerance::Absolute(Duration::from_millis(10));
+        assert!(Seconds(1.0).within(Seconds(1.005), tol));
+        assert!(!Seconds(1.0).within(Seconds(1.5), tol));
+    }
+
+    #[test]
+    fn seconds_within_relative() {
+        let (a, b) = (Seconds(1_545_136_342.0), Seconds(1_545_136_352.0));
+        assert!(a.within(b, Tolerance::Relative(10.0)));
+        assert!(!a.within(b, Tolerance::Absolute(Duration::from_secs(1))));
+        assert!(!Seconds(1.0).within(Seconds(2.0), Tolerance::Relative(10.0)));
+    }
+
+    #[test]
+    fn seconds_sub_saturating_reported() {
+        assert_eq!(
+            Seconds(10.5).sub_saturating_reported(Duration::from_secs(10)),
+            (Seconds(0.5), false)
+        );
+        assert_eq!(
+            Seconds(10.5).sub_saturating_reported(Duration::from_secs(11)),
+            (Seconds::EPOCH, true)
+        );
+        assert_eq!(
+            Seconds(-5.0).sub_saturating_reported(Duration::from_secs(1)),
+            (Seconds(-6.0), false)
+        );
+        let (nan, saturated) = Seconds(f64::NAN).sub_saturating_reported(Duration::from_secs(1));
+        assert!(nan.is_nan() && !saturated);
+        assert_eq!(
+            Seconds(f64::INFINITY).sub_saturating_reported(Duration::from_secs(1)),
+            (Seconds(f64::INFINITY), false)
+        );
+    }
+
+    #[test]
+    fn seconds_retention_bucket() {
+        let now = Seconds(1_545_136_342.0);
+        let ago = |secs: f64| Seconds(now.0 - secs);
+        assert_eq!(ago(-5.0).retention_bucket(now), RetentionBucket::UnderHour);
+        assert_eq!(
+            ago(59.0 * 60.0).retention_bucket(now),
+            RetentionBucket::UnderHour
+        );
+        assert_eq!(
+            ago(2.0 * 3600.0).retention_bucket(now),
+            RetentionBucket::UnderDay
+        );
+        assert_eq!(
+            ago(2.0 * 86400.0).retention_bucket(now),
+            RetentionBucket::UnderThirtyDays
+        );
+        assert_eq!(
+            ago(30.0 * 86400.0).retention_bucket(now),
+            RetentionBucket::ThirtyDaysOrOlder
+        );
+    }
+
+    #[test]
+    fn seconds_clamp_subsec_precision() {
+        assert_eq!(Seconds(1.23456).clamp_subsec_precision(3), Seconds(1.234));
+        assert_eq!(Seconds(-1.23456).clamp_subsec_precision(3), Seconds(-1.234));
+        assert_eq!(Seconds(1.23456).clamp_subsec_precision(0), Seconds(1.0));
+        assert_eq!(
+            Seconds(1234.5).clamp_subsec_precision(u32::MAX),
+            Seconds(1234.5)
+        );
+        assert_eq!(Seconds(1234.5).clamp_subsec_precision(300), Seconds(1234.5));
+    }
+
+    #[test]
+    fn seconds_average() {
+        assert_eq!(Seconds::average(&[]), None);
+        assert_eq!(
+            Seconds::average(&[Seconds(1_545_136_342.5)]),
+            Some(Seconds(1_545_136_342.5))
+        );
+        assert_eq!(
+            Seconds::average(&[
+                Seconds(1_545_136_340.0),
+                Seconds(1_545_136_342.0),
+                Seconds(1_545_136_347.0)
+            ]),
+            Some(Seconds(1_545_136_343.0))
+        );
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn seconds_with_jitter() {
+        use rand::{rngs::StdRng, SeedableRng};
+        let mut rng = StdRng::seed_from_u64(42);
+        let secs = Seconds(1_545_136_342.0);
+        let max = Duration::from_secs(30);
+        for _ in 0..100 {
+            let jittered = secs.with_jitter(max, &mut rng);
+            assert!(jittered.0 >= secs.0 && jittered.0 <= secs.0 + 30.0);
+        }
+        assert_eq!(secs.with_jitter(Duration::from_secs(0), &mut rng), secs);
+    }
+
+    #[test]
+    fn seconds_truncate_to_unit() {
+        let secs = Seconds(1_545_136_342.711_932);
+        assert_eq!(
+            secs.truncate_to_unit(Duration::from_secs(300)),
+            Seconds(1_545_136_200.0)
+        );
+        assert_eq!(secs.truncate_to_unit(Duration::from_secs(1)), secs.trunc());
+        assert_eq!(secs.truncate_to_unit(Duration::from_secs(0)), secs);
+    }
+
+    #[test]
+    fn seconds_cmp_duration() {
+        assert_eq!(Seconds(1.5), Duration::from_millis(1500));
+        assert_ne!(Seconds(1.5), Duration::from_millis(1501));
+        assert!(Seconds(1.5) < Duration::from_secs(2));
+        assert!(Seconds(1.5) > Duration::from_secs(1));
+    }
+
+    #[test]
+    fn seconds_sub_duration_saturates() {
+        let secs = Seconds(1.5);
+        assert_eq!(secs - Duration::from_secs(2), Seconds::EPOCH);
+    }
+
+    #[test]
+    fn seconds_sub_duration_before_epoch() {
+        let secs = Seconds(-5.0);
+        assert_eq!(secs - Duration::from_secs(1), Seconds(-6.0));
+        assert_eq!(secs - Duration::from_millis(500), Seconds(-5.5));
+        assert_eq!(secs - Duration::ZERO, secs);
+        assert!((Seconds(f64::NAN) - Duration::from_secs(1)).is_nan());
+    }
+
+    #[test]
+    fn seconds_checked_sub() {
+        let secs = Seconds(1.5);
+        assert_eq!(secs.checked_sub(Duration::from_secs(1)), Some(Seconds(0.5)));
+        assert_eq!(secs.checked_sub(Duration::from_secs(2)), None);
+        assert_eq!(
+            Seconds(-5.0).checked_sub(Duration::from_secs(1)),
+            Some(Seconds(-6.0))
+        );
+        assert!(Seconds(f64::NAN)
+            .checked_sub(Duration::from_secs(1))
+            .is_some_and(|secs| secs.is_nan()));
+    }
+
+    #[test]
+    fn seconds_from_hex_secs() {
+        assert_eq!(
+            Seconds::from_hex_secs("5C18A3D6"),
+            Ok(Seconds(1_545_118_678.0))
+        );
+        assert_eq!(
+            Seconds::from_hex_secs("0x5c18a3d6"),
+            Ok(Seconds(1_545_118_678.0))
+        );
+        let err = Seconds::from_hex_secs("5C18XYZ").expect_err("expected an error");
+        assert_eq!(err.to_string(), "invalid hexadecimal seconds: \"5C18XYZ\"");
+    }
+
+    #[test]
+    fn seconds_eq_secs() {
+        assert!(Seconds(1_545_136_342.1).eq_secs(Seconds(1_545_136_342.9)));
+        assert!(!Seconds(1_545_136_342.9).eq_secs(Seconds(1_545_136_343.1)));
+    }
+
+    #[test]
+    fn seconds_bytes_round_trip() {
+        for secs in &[
+            Seconds(1_545_136_342.711_932),
+            Seconds(-1.5),
+            Seconds(0.0),
+            Seconds(f64::from_bits(0x7ff8_0000_dead_beef)),
+        ] {
+            assert_eq!(
+                Seconds::from_le_bytes(secs.to_le_bytes()).0.to_bits(),
+                secs.0.to_bits()
+            );
+            assert_eq!(
+                Seconds::from_be_bytes(secs.to_be_bytes()).0.to_bits(),
+                secs.0.to_bits()
+            );
+        }
+        assert_eq!(Seconds(1.0).to_be_bytes(), [0x3f, 0xf0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(Seconds(1.0).to_le_bytes(), [0, 0, 0, 0, 0, 0, 0xf0, 0x3f]);
+    }
+
+    #[test]
+    fn seconds_since() {
+        let secs = Seconds(10.5);
+        assert_eq!(secs.since(Seconds(8.0)), Seconds(2.5));
+        assert_eq!(secs.since(Seconds(12.0)), Seconds(-1.5));
+        assert_eq!(secs.since(secs), Seconds(0.0));
+    }
+
+    #[test]
+    fn seconds_f32_round_trip() {
+        assert_eq!(Seconds::from_f32(Seconds(1.5).to_f32_lossy()), Seconds(1.5));
+        // at current epoch values f32 is only precise to 128 seconds
+        let secs = Seconds(1_545_136_342.711_932);
+        assert_eq!(
+            Seconds::from_f32(secs.to_f32_lossy()),
+            Seconds(1_545_136_384.0)
+        );
+    }
+
+    #[test]
+    fn seconds_clamp_future() {
+        let now = Seconds(1_545_136_342.0);
+        let allowance = Duration::from_secs(5);
+        assert_eq!(
+            Seconds(1_545_136_345.0).clamp_future(now, allowance),
+            Seconds(1_545_136_345.0)
+        );
+        assert_eq!(Seconds(1_545_136_347.5).clamp_future(now, allowance), now);
+        assert_eq!(Seconds(1_645_136_342.0).clamp_future(now, allowance), now);
+        let clock = FixedClock::new(now);
+        assert_eq!(
+            Seconds(1_545_136_400.0).clamp_future_with(&clock, allowance),
+            now
+        );
+    }
+
+    #[test]
+    fn seconds_bucket_index() {
+        let (origin, window) = (Seconds(100.0), Duration::from_secs(10));
+        assert_eq!(Seconds(100.0).bucket_index(origin, window), 0);
+        assert_eq!(Seconds(109.9).bucket_index(origin, window), 0);
+        assert_eq!(Seconds(115.0).bucket_index(origin, window), 1);
+        assert_eq!(Seconds(95.0).bucket_index(origin, window), -1);
+    }
+
+    #[test]
+    #[should_panic(expected = "bucket window must be non-zero")]
+    fn seconds_bucket_index_zero_window() {
+        let _ = Seconds(100.0).bucket_index(Seconds::EPOCH, Duration::from_secs(0));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn seconds_fails_to_deserialize_non_numbers() {
+        for (json, expected) in &[
+            (
+                "true",
+                "invalid type: boolean `true`, expected floating point seconds at line 1 column 4",
+            ),
+            (
+                "null",
+                "invalid type: null, expected floating point seconds at line 1 column 4",
+            ),
+            (
+                "\"soon\"",
+                "invalid type: string \"soon\", expected floating point seconds at line 1 column 6",
+            ),
+        ] {
+            match serde_json::from_str::<Seconds>(json) {
+                Err(err) => assert_eq!(err.to_string(), *expected),
+                Ok(other) => panic!("unexpected result {}", other),
+            }
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn seconds_visitor_rejects_bool_and_unit() {
+        use serde::de::{
+            value::{BoolDeserializer, Error, UnitDeserializer},
+            Deserialize,
+        };
+        let err = Seconds::deserialize(BoolDeserializer::<Error>::new(true))
+            .expect_err("expected an error");
+        assert_eq!(
+            err.to_string(),
+            "invalid type: boolean `true`, expected floating point seconds"
+        );
+        let err = Seconds::deserialize(UnitDeserializer::<Error>::new())
+            .expect_err("expected an error");
+        assert_eq!(
+            err.to_string(),
+            "invalid type: unit value, expected floating point seconds"
+        );
+    }
+
+    #[test]
+    fn seconds_from_duration_since_epoch() {
+        assert_eq!(
+            Seconds::from_duration_since_epoch(Duration::new(1_545_136_342, 500_000_000)),
+            Seconds(1_545_136_342.5)
+        );
+    }
+
+    #[test]
+    fn seconds_cmp_approx() {
+        let epsilon = Duration::from_millis(10);
+        assert_eq!(
+            Seconds(1.0).cmp_approx(Seconds(1.005), epsilon),
+            Ordering::Equal
+        );
+        assert_eq!(
+            Seconds(1.0).cmp_approx(Seconds(2.0), epsilon),
+            Ordering::Less
+        );
+        assert_eq!(
+            Seconds(2.0).cmp_approx(Seconds(1.0), epsilon),
+            Ordering::Greater
+        );
+    }
+
+    #[test]
+    fn seconds_resolution_at() {
+        let resolution = Seconds(1_545_136_342.711_932).resolution_at();
+        assert!(resolution < Duration::from_micros(1));
+        assert!(resolution > Duration::from_nanos(1));
+        assert_eq!(Seconds(-1_545_136_342.711_932).resolution_at(), resolution);
+        assert_eq!(Seconds(f64::INFINITY).resolution_at(), Duration::MAX);
+    }
+
+    #[test]
+    fn seconds_try_add_signed() {
+        let secs = Seconds(10.5);
+        assert_eq!(secs.try_add_signed(5), Some(Seconds(15.5)));
+        assert_eq!(secs.try_add_signed(-5), Some(Seconds(5.5)));
+        assert_eq!(secs.try_add_signed(-11), None);
+        assert_eq!(Seconds(f64::NAN).try_add_signed(1), None);
+    }
+
+    #[test]
+    fn seconds_to_string_with_unit() {
+        let secs = Seconds(1_545_136_342.711_932);
+        assert_eq!(secs.to_string_with_unit(), "1545136342.711932s");
+        assert_eq!(secs.to_string(), "1545136342.711932");
+    }
+
+    #[test]
+    fn seconds_from_str() {
+        assert_eq!(
+            "1545136342.711932".parse(),
+            Ok(Seconds(1_545_136_342.711_932))
+        );
+        assert!("NaN".parse::<Seconds>().is_err());
+        let err = "soon".parse::<Seconds>().expect_err("expected an error");
+        assert_eq!(err.to_string(), "invalid seconds: \"soon\"");
+    }
+
+    #[test]
+    fn seconds_try_from_str() {
+        assert_eq!(Seconds::try_from("1.5"), Ok(Seconds(1.5)));
+        assert_eq!(Seconds::try_from("soon"), "soon".parse::<Seconds>());
+        assert!(Seconds::try_from("soon").is_err());
+    }
+
+    #[test]
+    fn seconds_nanos_since_epoch() {
+        assert_eq!(
+            Seconds(1_545_136_342.0).nanos_since_epoch(),
+            1_545_136_342_000_000_000
+        );
+        assert_eq!(Seconds(-1.5).nanos_since_epoch(), -1_500_000_000);
+        assert_eq!(Seconds(2.4e-9).nanos_since_epoch(), 2);
+        assert_eq!(Seconds(2.6e-9).nanos_since_epoch(), 3);
+        // well beyond an i64 of seconds
+        assert_eq!(Seconds(1e19).nanos_since_epoch(), 10i128.pow(28));
+        assert_eq!(Seconds(-1e19).nanos_since_epoch(), -(10i128.pow(28)));
+        assert_eq!(Seconds(1e30).nanos_since_epoch(), i128::MAX);
+        assert_eq!(Seconds(-1e30).nanos_since_epoch(), i128::MIN);
+        assert_eq!(Seconds(f64::INFINITY).nanos_since_epoch(), i128::MAX);
+        assert_eq!(Seconds(f64::NEG_INFINITY).nanos_since_epoch(), i128::MIN);
+        assert_eq!(Seconds(f64::NAN).nanos_since_epoch(), 0);
+    }
+
+    #[test]
+    fn seconds_from_nanos_i128() {
+        assert_eq!(
+            Seconds::from_nanos_i128(1_545_136_342_500_000_000),
+            Seconds(1_545_136_342.5)
+        );
+        assert_eq!(Seconds::from_nanos_i128(-1_500_000_000), Seconds(-1.5));
+    }
+
+    #[test]
+    fn seconds_clamp_to_valid() {
+        let fallback = Seconds::EPOCH;
+        assert_eq!(Seconds(f64::NAN).clamp_to_valid(fallback), fallback);
+        assert_eq!(Seconds(f64::INFINITY).clamp_to_valid(fallback), fallback);
+        assert_eq!(Seconds(1.5).clamp_to_valid(fallback), Seconds(1.5));
+    }
+
+    #[test]
+    fn seconds_ord_key() {
+        let values = [
+            Seconds(f64::NEG_INFINITY),
+            Seconds(-1_545_136_342.5),
+            Seconds(-1.0),
+            Seconds(-0.0),
+            Seconds(0.0),
+            Seconds(1.0),
+            Seconds(1_545_136_342.5),
+            Seconds(f64::INFINITY),
+        ];
+        for a in &values {
+            assert_eq!(
+                Seconds::from_ord_key(a.ord_key()).0.to_bits(),
+                a.0.to_bits()
+            );
+            for b in &values {
+                assert_eq!(a.0.total_cmp(&b.0), a.ord_key().cmp(&b.ord_key()));
+            }
+        }
+    }
+
+    #[test]
+    fn seconds_checked_into_duration() {
+        assert_eq!(Seconds(-1.5).checked_into_duration(), None);
+        assert_eq!(Seconds(f64::NAN).checked_into_duration(), None);
+        assert_eq!(
+            Seconds(1.5).checked_into_duration(),
+            Some(Duration::from_millis(1500))
+        );
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn seconds_range_distribution() {
+        use super::SecondsRange;
+        use rand::{distributions::Distribution, rngs::StdRng, SeedableRng};
+        let range = SecondsRange {
+            lo: Seconds(1_545_136_342.0),
+            hi: Seconds(1_545_139_942.0),
+        };
+        let samples: Vec<_> = range
+            .sample_iter(StdRng::seed_from_u64(7))
+            .take(1_000)
+            .collect();
+        assert!(samples
+            .iter()
+            .all(|secs| secs.0 >= range.lo.0 && secs.0 <= range.hi.0));
+        // uniform samples should spread across the hour rather than bunch up
+        assert!(samples.iter().any(|secs| secs.0 < range.lo.0 + 600.0));
+        assert!(samples.iter().any(|secs| secs.0 > range.hi.0 - 600.0));
+        let instant = SecondsRange {
+            lo: range.lo,
+            hi: range.lo,
+        };
+        assert_eq!(instant.sample(&mut StdRng::seed_from_u64(7)), range.lo);
+    }
+
+    #[test]
+    fn seconds_split_range() {
+        assert_eq!(
+            Seconds::split_range(Seconds(0.0), Seconds(10.0), 2),
+            vec![(Seconds(0.0), Seconds(5.0)), (Seconds(5.0), Seconds(10.0))]
+        );
+        let (from, to) = (Seconds(1_545_136_342.0), Seconds(1_545_136_352.0));
+        let parts = Seconds::split_range(from, to, 3);
+        assert_eq!(parts.len(), 3);
+        assert_eq!(parts[0].0, from);
+        assert_eq!(parts[2].1, to);
+        assert!(parts.windows(2).all(|pair| pair[0].1 == pair[1].0));
+        assert!(Seconds::split_range(from, to, 0).is_empty());
+        assert!(Seconds::split_range(to, from, 3).is_empty());
+    }
+
+    #[test]
+    fn seconds_min_max() {
+        assert_eq!(Seconds::min_max(Vec::new()), None);
+        assert_eq!(
+            Seconds::min_max(vec![Seconds(1.5)]),
+            Some((Seconds(1.5), Seconds(1.5)))
+        );
+        assert_eq!(
+            Seconds::min_max(vec![
+                Seconds(3.0),
+                Seconds(-1.0),
+                Seconds(7.5),
+                Seconds(2.0)
+            ]),
+            Some((Seconds(-1.0), Seconds(7.5)))
+        );
+    }
+
+    #[test]
+    fn seconds_with_subsec_nanos() {
+        assert_eq!(
+            Seconds(1_545_136_342.0).with_subsec_nanos(500_000_000),
+            Seconds(1_545_136_342.5)
+        );
+        assert_eq!(
+            Seconds(1_545_136_342.711_932).with_subsec_nanos(250_000_000),
+            Seconds(1_545_136_342.25)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "must be less than 1_000_000_000")]
+    fn seconds_with_subsec_nanos_out_of_range() {
+        let _ = Seconds(1.0).with_subsec_nanos(1_000_000_000);
+    }
+
+    #[test]
+    fn seconds_rounding() {
+        let secs = Seconds(-1.5);
+        assert_eq!(secs.trunc(), Seconds(-1.0));
+        assert_eq!(secs.round(), Seconds(-2.0));
+        assert_eq!(secs.floor(), Seconds(-2.0));
+        assert_eq!(secs.ceil(), Seconds(-1.0));
+    }
+
+    #[test]
+    fn seconds_rounding_in_place() {
+        struct Event {
+            at: Seconds,
+        }
+        let mut event = Event { at: Seconds(1.5) };
+        event.at.trunc_mut();
+        assert_eq!(event.at, Seconds(1.0));
+        let mut secs = Seconds(1.5);
+        secs.round_mut();
+        assert_eq!(secs, Seconds(2.0));
+        let mut secs = Seconds(1.5);
+        secs.floor_mut();
+        assert_eq!(secs, Seconds(1.0));
+        let mut secs = Seconds(1.25);
+        secs.ceil_mut();
+        assert_eq!(secs, Seconds(2.0));
+    }
+
+    #[test]
+    fn seconds_debug() {
+        assert_eq!(format!("{:?}", Seconds(1.5)), "Seconds(1.5)");
+        assert_eq!(
+            format!("{:#?}", Seconds(1.5)),
+            "Seconds {\n    secs: 1.5,\n    bits: 0x3ff8000000000000,\n    finite: true,\n}"
+        );
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn seconds_from_json_value() {
+        use serde_json::json;
+        assert_eq!(Seconds::from_json_value(&json!(1.5)), Ok(Seconds(1.5)));
+        assert_eq!(
+            Seconds::from_json_value(&json!(1_545_136_342)),
+            Ok(Seconds(1_545_136_342.0))
+        );
+        assert_eq!(Seconds::from_json_value(&json!("1.5")), Ok(Seconds(1.5)));
+        let err = Seconds::from_json_value(&json!({ "at": 1.5 })).expect_err("expected an error");
+        assert_eq!(err.to_string(), r#"invalid json seconds: "{\"at\":1.5}""#);
+        assert!(Seconds::from_json_value(&json!("soon")).is_err());
+    }
+
+    #[test]
+    fn seconds_days_between() {
+        let start = Seconds(1_545_136_342.0);
+        assert_eq!(start.days_between(Seconds(start.0 + 86_400.0)), 1);
+        assert_eq!(start.days_between(Seconds(start.0 + 86_399.5)), 0);
+        assert_eq!(Seconds(start.0 + 2.5 * 86_400.0).days_between(start), -2);
+    }
+
+    #[test]
+    fn seconds_hours_between() {
+        let start = Seconds(1_545_136_342.0);
+        assert_eq!(start.hours_between(Seconds(start.0 + 3_600.0)), 1);
+        assert_eq!(start.hours_between(Seconds(start.0 + 3_599.5)), 0);
+        assert_eq!(Seconds(start.0 + 7_300.0).hours_between(start), -2);
+    }
+
+    #[test]
+    fn seconds_overflowing_add() {
+        assert_eq!(
+            Seconds(1_545_136_342.5).overflowing_add(Duration::from_secs(1)),
+            (Seconds(1_545_136_343.5), false)
+        );
+        assert_eq!(
+            Seconds(Seconds::MAX.0 - 1.0).overflowing_add(Duration::from_secs(1)),
+            (Seconds::MAX, false)
+        );
+        assert_eq!(
+            Seconds::MAX.overflowing_add(Duration::from_secs(1)),
+            (Seconds(Seconds::MAX.0 + 1.0), true)
+        );
+        // the largest f64 below f64::MAX
+        let below_max = f64::from_bits(f64::MAX.to_bits() - 1);
+        assert_eq!(
+            Seconds(below_max).overflowing_add(Duration::from_secs(1)),
+            (Seconds(below_max), true)
+        );
+        assert_eq!(
+            Seconds(1e308).overflowing_add(Duration::MAX),
+            (Seconds(1e308), true)
+        );
+        assert_eq!(
+            Seconds(f64::MAX).overflowing_add(Duration::ZERO),
+            (Seconds(f64::MAX), true)
+        );
+        assert_eq!(
+            Seconds(-f64::MAX).overflowing_add(Duration::from_secs(1)),
+            (Seconds(-f64::MAX), false)
+        );
+        assert!(Seconds(f64::INFINITY).overflowing_add(Duration::from_secs(1)).1);
+        assert!(!Seconds(f64::NAN).overflowing_add(Duration::from_secs(1)).1);
+    }
+
+    #[test]
+    fn seconds_to_instant_estimate() {
+        let estimate = (Seconds::now() + Duration::from_secs(5))
+            .to_instant_estimate()
+            .expect("failed to estimate instant");
+        let expected = Instant::now() + Duration::from_secs(5);
+        let skew = if estimate > expected {
+            estimate - expected
+        } else {
+            expected - estimate
+        };
+        assert!(skew < Duration::from_secs(1));
+        assert_eq!(Seconds(f64::NAN).to_instant_estimate(), None);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn seconds_precision_serialize() {
+        let seconds = Seconds(1_545_136_342.711_932);
+        let json = |digits| {
+            serde_json::to_string(&SecondsPrecision { seconds, digits })
+                .expect("failed to serialize")
+        };
+        assert_eq!(json(0), "1545136343.0");
+        assert_eq!(json(3), "1545136342.712");
+        assert_eq!(json(6), "1545136342.711932");
+        for digits in 0..=24 {
+            assert_eq!(
+                json(digits),
+                serde_json::to_string(&seconds.as_secs_f64_rounded(u32::from(digits)))
+                    .expect("failed to serialize")
+            );
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn seconds_with_serialize() {
+        let value = Seconds(1_545_136_342.711_932);
+        let json = |format| {
+            serde_json::to_string(&SecondsWith { value, format }).expect("failed to serialize")
+        };
+        assert_eq!(json(SecondsFormat::FloatSecs), "1545136342.711932");
+        assert_eq!(json(SecondsFormat::IntSecs), "1545136342");
+        assert_eq!(json(SecondsFormat::IntMillis), "1545136342712");
+        #[cfg(feature = "date")]
+        assert_eq!(
+            json(SecondsFormat::Rfc3339),
+            r#""2018-12-18T12:32:22.711932Z""#
+        );
+        assert!(serde_json::to_string(&SecondsWith {
+            value: Seconds(f64::NAN),
+            format: SecondsFormat::IntSecs,
+        })
+        .is_err());
+    }
+
+    #[test]
+    fn seconds_min_max_constants() {
+        assert!(Seconds::MAX.is_finite() && Seconds::MIN.is_finite());
+        assert_eq!(Seconds::MAX.0, 2f64.powi(53) - 1.0);
+        assert_eq!(Seconds::MIN.0, -Seconds::MAX.0);
+    }
+
+    #[test]
+    fn seconds_saturating_add() {
+        assert_eq!(
+            Seconds(1.5).saturating_add(Duration::from_secs(1)),
+            Seconds(2.5)
+        );
+        assert_eq!(
+            Seconds::MAX.saturating_add(Duration::from_secs(1)),
+            Seconds::MAX
+        );
+        assert_eq!(
+            Seconds(f64::MAX).saturating_add(Duration::from_secs(1)),
+            Seconds::MAX
+        );
+        assert!(Seconds(f64::NAN)
+            .saturating_add(Duration::from_secs(1))
+            .is_nan());
+    }
+
+    #[test]
+    fn seconds_rem_duration() {
+        assert_eq!(
+            Seconds(125.0) % Duration::from_secs(60),
+            Duration::from_secs(5)
+        );
+        assert_eq!(
+            Seconds(-55.0) % Duration::from_secs(60),
+            Duration::from_secs(5)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "remainder window must be non-zero")]
+    fn seconds_rem_zero_duration() {
+        let _ = Seconds(125.0) % Duration::from_secs(0);
+    }
+
+    #[test]
+    fn seconds_checked_duration_since() {
+        let secs = Seconds(10.5);
+        assert_eq!(
+            secs.checked_duration_since(Seconds(8.0)),
+            Some(Duration::from_millis(2500))
+        );
+        assert_eq!(secs.checked_duration_since(Seconds(12.0)), None);
+        assert_eq!(
+            secs.checked_duration_since(secs),
+            Some(Duration::from_secs(0))
+        );
+    }
+
+    #[test]
+    fn seconds_is_js_safe_millis() {
+        assert!(Seconds(1_545_136_342.711).is_js_safe_millis());
+        assert!(Seconds(-1_545_136_342.711).is_js_safe_millis());
+        assert!(!Seconds(1e13).is_js_safe_millis());
+        assert!(!Seconds(f64::NAN).is_js_safe_millis());
+        assert!(!Seconds(f64::INFINITY).is_js_safe_millis());
+    }
+
+    #[test]
+    fn seconds_span_contains() {
+        let (start, end) = (Seconds(10.0), Seconds(20.0));
+        assert!(Seconds::span_contains(Seconds(15.0), start, end));
+        assert!(Seconds::span_contains(start, start, end));
+        assert!(!Seconds::span_contains(end, start, end));
+        assert!(!Seconds::span_contains(Seconds(25.0), start, end));
+        assert!(!Seconds::span_contains(Seconds(5.0), start, end));
+    }
+
+    #[test]
+    fn seconds_spans_overlap() {
+        let a = (Seconds(10.0), Seconds(20.0));
+        assert!(Seconds::spans_overlap(a, (Seconds(15.0), Seconds(25.0))));
+        assert!(Seconds::spans_overlap(a, (Seconds(12.0), Seconds(18.0))));
+        assert!(!Seconds::spans_overlap(a, (Seconds(20.0), Seconds(30.0))));
+        assert!(!Seconds::spans_overlap(a, (Seconds(0.0), Seconds(10.0))));
+    }
+
+    #[test]
+    fn seconds_round_ties_even() {
+        assert_eq!(Seconds(0.5).round_ties_even(), Seconds(0.0));
+        assert_eq!(Seconds(1.5).round_ties_even(), Seconds(2.0));
+        assert_eq!(Seconds(2.5).round_ties_even(), Seconds(2.0));
+        assert_eq!(Seconds(2.6).round_ties_even(), Seconds(3.0));
+        assert_eq!(Seconds(2.5).round(), Seconds(3.0));
+    }
+
+    #[test]
+    fn seconds_fmt_duration_since() {
+        let start = Seconds(1_545_136_342.0);
+        assert_eq!(
+            Seconds(1_545_136_342.25).fmt_duration_since(start),
+            "0.250s"
+        );
+        assert_eq!(
+            Seconds(1_545_136_354.5).fmt_duration_since(start),
+            "12.500s"
+        );
+        assert_eq!(
+            Seconds(1_545_136_341.0).fmt_duration_since(start),
+            "-1.000s"
+        );
+    }
+
+    #[test]
+    fn seconds_normalize() {
+        let fallback = Seconds(1.0);
+        assert_eq!(Seconds(f64::NAN).normalize(fallback), fallback);
+        assert_eq!(Seconds(f64::NEG_INFINITY).normalize(fallback), fallback);
+        let zero = Seconds(-0.0).normalize(fallback);
+        assert_eq!(zero.to_le_bytes(), Seconds(0.0).to_le_bytes());
+        assert_eq!(Seconds(1.5).normalize(fallback), Seconds(1.5));
+        assert_eq!(Seconds(-0.0).normalize(Seconds(-0.0)).0.to_bits(), 0);
+    }
+
+    #[test]
+    fn seconds_to_compact_string() {
+        assert_eq!(Seconds(1.5).to_compact_string(), "1.5");
+        assert_eq!(Seconds(2.0).to_compact_string(), "2");
+        assert_eq!(Seconds(1e21).to_compact_string(), "1000000000000000000000");
+        assert_eq!(Seconds(1e-7).to_compact_string(), "0.0000001");
+        let secs = Seconds(1_545_136_342.711_932_3);
+        let parsed: f64 = secs.to_compact_string().parse().expect("failed to parse");
+        assert_eq!(parsed.to_bits(), secs.0.to_bits());
+    }
+
+    #[test]
+    fn seconds_from_millis_str() {
+        assert_eq!(
+            Seconds::from_millis_str("1545136342711"),
+            Ok(Seconds(1_545_136_342.711))
+        );
+        assert_eq!(Seconds::from_millis_str("-1500"), Ok(Seconds(-1.5)));
+        assert_eq!(Seconds::from_millis_str("2500.5"), Ok(Seconds(2.5005)));
+        assert_eq!(
+            Seconds::from_millis_str("soon").unwrap_err().to_string(),
+            r#"invalid milliseconds: "soon""#
+        );
+        assert!(Seconds::from_millis_str("inf").is_err());
+        assert!(Seconds::from_millis_str("").is_err());
+    }
+
+    #[test]
+    fn seconds_timespec_parts() {
+        assert_eq!(Seconds(1.25).to_timespec_parts(), (1, 250_000_000));
+        assert_eq!(Seconds(-1.25).to_timespec_parts(), (-2, 750_000_000));
+        assert_eq!(Seconds(1.999_999_999_9).to_timespec_parts(), (2, 0));
+        assert_eq!(Seconds::from_timespec_parts(1, 250_000_000), Seconds(1.25));
+        assert_eq!(
+            Seconds::from_timespec_parts(-2, 750_000_000),
+            Seconds(-1.25)
+        );
+        assert_eq!(Seconds::from_timespec_parts(0, 1_500_000_000), Seconds(1.5));
+        assert_eq!(
+            Seconds::from_timespec_parts(i64::MAX, 1_500_000_000),
+            Seconds(i64::MAX as f64)
+        );
+        let (sec, nsec) = Seconds(1_545_136_342.5).to_timespec_parts();
+        assert_eq!(
+            Seconds::from_timespec_parts(sec, nsec),
+            Seconds(1_545_136_342.5)
+        );
+    }
+
+    #[test]
+    fn seconds_proto_duration_parts() {
+        assert_eq!(Seconds(1.25).to_proto_duration_parts(), (1, 250_000_000));
+        assert_eq!(Seconds(-1.25).to_proto_duration_parts(), (-1, -250_000_000));
+        assert_eq!(Seconds(-0.5).to_proto_duration_parts(), (0, -500_000_000));
+        assert_eq!(Seconds(-1.999_999_999_9).to_proto_duration_parts(), (-2, 0));
+        assert_eq!(Seconds(0.0).to_proto_duration_parts(), (0, 0));
+        assert_eq!(
+            Seconds::from_proto_duration_parts(1, 250_000_000),
+            Seconds(1.25)
+        );
+        assert_eq!(
+            Seconds::from_proto_duration_parts(-1, -250_000_000),
+            Seconds(-1.25)
+        );
+        assert_eq!(
+            Seconds::from_proto_duration_parts(-1, -1_500_000_000),
+            Seconds(-2.5)
+        );
+        assert_eq!(
+            Seconds::from_proto_duration_parts(i64::MAX, 1_500_000_000),
+            Seconds(i64::MAX as f64)
+        );
+        let (secs, nanos) = Seconds(-3_600.75).to_proto_duration_parts();
+        assert_eq!(
+            Seconds::from_proto_duration_parts(secs, nanos),
+            Seconds(-3_600.75)
+        );
+    }
+
+    #[test]
+    fn seconds_checked_from_nanos() {
+        assert_eq!(Seconds::checked_from_nanos(0), Some(Seconds(0.0)));
+        assert_eq!(
+            Seconds::checked_from_nanos(1_545_136_342_500_000_000),
+            Some(Seconds(1_545_136_342.5))
+        );
+        assert_eq!(Seconds::checked_from_nanos(1_545_136_342_000_000_001), None);
+        assert_eq!(Seconds::checked_from_nanos(u128::MAX), None);
+    }
+
+    #[test]
+    fn seconds_clock_resolution() {
+        let started = Instant::now();
+        let resolution = Seconds::clock_resolution();
+        // the probe is bounded to about 20ms
+        assert!(started.elapsed() < Duration::from_secs(1));
+        assert!(resolution > Duration::from_secs(0));
+        assert!(resolution < Duration::from_secs(1));
+        assert_eq!(Seconds::clock_resolution(), resolution);
+    }
+
+    #[test]
+    fn seconds_debug_checked_passes_finite() {
+        assert_eq!(Seconds(1.5).debug_checked(), Seconds(1.5));
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "non-finite Seconds: NaN")]
+    fn seconds_debug_checked_panics_on_nan() {
+        let _ = Seconds(f64::NAN).debug_checked();
+    }
+
+    #[test]
+    fn seconds_timeval_parts() {
+        assert_eq!(Seconds(1.25).to_timeval_parts(), (1, 250_000));
+        assert_eq!(Seconds(-1.25).to_timeval_parts(), (-2, 750_000));
+        assert_eq!(Seconds(1.999_999_9).to_timeval_parts(), (2, 0));
+        assert_eq!(Seconds::from_timeval_parts(1, 250_000), Seconds(1.25));
+        assert_eq!(Seconds::from_timeval_parts(-2, 750_000), Seconds(-1.25));
+        assert_eq!(Seconds::from_timeval_parts(0, 1_500_000), Seconds(1.5));
+        assert_eq!(Seconds::from_timeval_parts(0, -250_000), Seconds(-0.25));
+        assert_eq!(
+            Seconds::from_timeval_parts(i64::MAX, 2_000_000),
+            Seconds(i64::MAX as f64)
+        );
+        let (sec, usec) = Seconds(1_545_136_342.711_932).to_timeval_parts();
+        assert_eq!((sec, usec), (1_545_136_342, 711_932));
+        assert_eq!(
+            Seconds::from_timeval_parts(sec, usec),
+            Seconds(1_545_136_342.711_932)
+        );
+    }
+
+    #[test]
+    fn seconds_age_within() {
+        let clock = FixedClock::new(Seconds(1_000.0));
+        let max_age = Duration::from_secs(60);
+        assert!(Seconds(970.0).age_within_with(&clock, max_age));
+        assert!(Seconds(940.0).age_within_with(&clock, max_age));
+        assert!(!Seconds(939.0).age_within_with(&clock, max_age));
+        assert!(Seconds(2_000.0).age_within_with(&clock, max_age));
+        assert!(!Seconds(f64::NAN).age_within_with(&clock, max_age));
+        assert!(Seconds::now().age_within(max_age));
+    }
+
+    #[test]
+    fn seconds_as_duration_since() {
+        assert_eq!(
+            Seconds(12.5).as_duration_since(Seconds(10.0)),
+            (Duration::from_millis(2500), true)
+        );
+        assert_eq!(
+            Seconds(10.0).as_duration_since(Seconds(12.5)),
+            (Duration::from_millis(2500), false)
+        );
+        assert_eq!(
+            Seconds(10.0).as_duration_since(Seconds(10.0)),
+            (Duration::ZERO, false)
+        );
+        assert_eq!(
+            Seconds(f64::INFINITY).as_duration_since(Seconds(10.0)),
+            (Duration::MAX, true)
+        );
+        assert_eq!(
+            Seconds(f64::NAN).as_duration_since(Seconds(10.0)),
+            (Duration::ZERO, false)
+        );
+    }
+
+    #[test]
+    fn seconds_quantize_with() {
+        let unit = Duration::from_secs(10);
+        let cases = [
+            (RoundingMode::Trunc, 20.0, -20.0),
+            (RoundingMode::Floor, 20.0, -30.0),
+            (RoundingMode::Ceil, 30.0, -20.0),
+            (RoundingMode::Nearest, 30.0, -30.0),
+            (RoundingMode::NearestEven, 20.0, -20.0),
+        ];
+        for (mode, positive, negative) in cases {
+            assert_eq!(Seconds(25.0).quantize_with(unit, mode), Seconds(positive));
+            assert_eq!(Seconds(-25.0).quantize_with(unit, mode), Seconds(negative));
+        }
+        assert_eq!(
+            Seconds(26.0).quantize_with(unit, RoundingMode::NearestEven),
+            Seconds(30.0)
+        );
+        assert_eq!(
+            Seconds(25.0).quantize_with(Duration::ZERO, RoundingMode::Ceil),
+            Seconds(25.0)
+        );
+    }
+
+    #[test]
+    fn seconds_epoch_day() {
+        assert_eq!(Seconds::EPOCH.epoch_day(), 0);
+        assert_eq!(Seconds(86_399.9).epoch_day(), 0);
+        assert_eq!(Seconds(86_400.0).epoch_day(), 1);
+        assert_eq!(Seconds(1_545_136_342.711).epoch_day(), 17_883);
+        assert_eq!(Seconds(-0.5).epoch_day(), -1);
+        assert_eq!(Seconds(-86_400.5).epoch_day(), -2);
+    }
+
+    #[test]
+    fn seconds_to_fixed_point() {
+        assert_eq!(
+            Seconds(1_545_136_342.711_932).to_fixed_point(6, RoundingMode::Nearest),
+            1_545_136_342_711_932
+        );
+        // 1 + 2^-21 is exactly 1.000000476837158203125
+        let secs = Seconds(1.0 + 2f64.powi(-21));
+        assert_eq!(secs.to_fixed_point(6, RoundingMode::Floor), 1_000_000);
+        assert_eq!(secs.to_fixed_point(6, RoundingMode::Nearest), 1_000_000);
+        assert_eq!(secs.to_fixed_point(6, RoundingMode::Ceil), 1_000_001);
+        assert_eq!(secs.to_fixed_point(7, RoundingMode::Nearest), 10_000_005);
+        assert_eq!(Seconds(-1.5).to_fixed_point(0, RoundingMode::Trunc), -1);
+        assert_eq!(Seconds(-1.5).to_fixed_point(0, RoundingMode::Floor), -2);
+        assert_eq!(Seconds(2.5).to_fixed_point(0, RoundingMode::NearestEven), 2);
+    }
+
+    #[test]
+    fn seconds_to_fixed_point_saturates() {
+        assert_eq!(
+            Seconds(1e300).to_fixed_point(6, RoundingMode::Nearest),
+            i64::MAX
+        );
+        assert_eq!(
+            Seconds(-1e300).to_fixed_point(6, RoundingMode::Nearest),
+            i64::MIN
+        );
+        assert_eq!(
+            Seconds(1.0).to_fixed_point(400, RoundingMode::Nearest),
+            i64::MAX
+        );
+        assert_eq!(
+            Seconds(f64::NAN).to_fixed_point(6, RoundingMode::Nearest),
+            0
+        );
+    }
+
+    #[test]
+    fn seconds_from_fixed_point() {
+        assert_eq!(
+            Seconds::from_fixed_point(1_545_136_342_711_932, 6),
+            Seconds(1_545_136_342.711_932)
+        );
+        assert_eq!(Seconds::from_fixed_point(-1_500, 3), Seconds(-1.5));
+        assert_eq!(Seconds::from_fixed_point(42, 0), Seconds(42.0));
+        assert_eq!(Seconds::from_fixed_point(15, 19), Seconds(1.5e-18));
+    }
+
+    #[cfg(feature = "test-clock")]
+    #[test]
+    fn seconds_freeze() {
+        let frozen = Seconds(1_545_136_342.711_932);
+        Seconds::freeze(frozen);
+        assert_eq!(Seconds::now(), frozen);
+        assert_eq!(Seconds::now(), frozen);
+        assert_eq!(Seconds::now_coarse(), frozen);
+        // other threads are unaffected
+        let elsewhere = std::thread::spawn(Seconds::now)
+            .join()
+            .expect("thread panicked");
+        assert!(elsewhere.0 > frozen.0);
+        Seconds::unfreeze();
+        assert!(Seconds::now().0 > frozen.0);
+    }
+
+    #[test]
+    fn seconds_parse_with_unit() {
+        assert_eq!(Seconds::parse_with_unit("1.5s"), Ok(Seconds(1.5)));
+        assert_eq!(Seconds::parse_with_unit("1500ms"), Ok(Seconds(1.5)));
+        assert_eq!(Seconds::parse_with_unit("1500000us"), Ok(Seconds(1.5)));
+        assert_eq!(Seconds::parse_with_unit("1500000000ns"), Ok(Seconds(1.5)));
+        assert_eq!(Seconds::parse_with_unit("1.5"), Ok(Seconds(1.5)));
+        assert_eq!(
+            Seconds::parse_with_unit("1.5h").unwrap_err().to_string(),
+            r#"invalid seconds with an s, ms, us or ns unit: "1.5h""#
+        );
+        assert!(Seconds::parse_with_unit("ms").is_err());
+        assert!(Seconds::parse_with_unit("infs").is_err());
+    }
+
+    #[test]
+    fn seconds_checked_mul() {
+        assert_eq!(Seconds(10.0).checked_mul(1.5), Some(Seconds(15.0)));
+        assert_eq!(Seconds(10.0).checked_mul(-0.5), Some(Seconds(-5.0)));
+        assert_eq!(Seconds(1e300).checked_mul(1e10), None);
+        assert_eq!(Seconds(10.0).checked_mul(f64::NAN), None);
+    }
+
+    #[test]
+    fn seconds_checked_div() {
+        assert_eq!(Seconds(15.0).checked_div(1.5), Some(Seconds(10.0)));
+        assert_eq!(Seconds(10.0).checked_div(0.0), None);
+        assert_eq!(Seconds(0.0).checked_div(0.0), None);
+        assert_eq!(Seconds(1e300).checked_div(1e-10), None);
+    }
+
+    #[test]
+    fn seconds_log2_age_bucket() {
+        let now = Seconds(1_000.0);
+        assert_eq!(Seconds(999.5).log2_age_bucket(now), 0);
+        assert_eq!(Seconds(999.0).log2_age_bucket(now), 0);
+        assert_eq!(Seconds(998.0).log2_age_bucket(now), 1);
+        assert_eq!(Seconds(997.0).log2_age_bucket(now), 1);
+        assert_eq!(Seconds(990.0).log2_age_bucket(now), 3);
+        assert_eq!(Seconds(2_000.0).log2_age_bucket(now), 0);
+        assert_eq!(Seconds(f64::NAN).log2_age_bucket(now), 0);
+    }
+
+    #[test]
+    fn seconds_saturating_millis_u64() {
+        assert_eq!(
+            Seconds(1_545_136_342.711_6).saturating_millis_u64(),
+            1_545_136_342_712
+        );
+        assert_eq!(Seconds(-1.5).saturating_millis_u64(), 0);
+        assert_eq!(Seconds(f64::NAN).saturating_millis_u64(), 0);
+        assert_eq!(Seconds(1e300).saturating_millis_u64(), u64::MAX);
+        assert_eq!(Seconds(f64::INFINITY).saturating_millis_u64(), u64::MAX);
+    }
+
+    #[test]
+    fn seconds_round_trips_at_scale() {
+        let millis = Seconds::from_fixed_point(1_545_136_342_711, 3);
+        assert!(millis.round_trips_at_scale(3));
+        assert!(millis.round_trips_at_scale(6));
+        assert!(!millis.round_trips_at_scale(2));
+        assert!(!millis.round_trips_at_scale(0));
+        assert!(Seconds(42.0).round_trips_at_scale(0));
+        assert!(!Seconds(f64::NAN).round_trips_at_scale(3));
+    }
+
+    #[test]
+    fn seconds_now_with_source() {
+        for source in [
+            ClockSource::Wall,
+            ClockSource::Coarse,
+            ClockSource::MonotonicAnchored,
+        ] {
+            let before = Seconds::now();
+            let now = Seconds::now_with_source(source);
+            // coarse clocks may lag a few milliseconds behind
+            assert!((now.0 - before.0).abs() < 1.0, "{:?} read {}", source, now);
+        }
+        let first = Seconds::now_with_source(ClockSource::MonotonicAnchored);
+        let second = Seconds::now_with_source(ClockSource::MonotonicAnchored);
+        assert!(second.0 >= first.0);
+    }
+
+    #[test]
+    fn seconds_diff_f64() {
+        let start = Seconds(1_545_136_342.0);
+        let later = Seconds(1_545_136_342.0 + 90.0 * 60.0);
+        assert_eq!(start.diff_hours_f64(later), 1.5);
+        assert_eq!(start.diff_minutes_f64(later), 90.0);
+        assert_eq!(start.diff_days_f64(later), 0.0625);
+        assert_eq!(later.diff_hours_f64(start), -1.5);
+        assert_eq!(later.diff_minutes_f64(start), -90.0);
+        assert_eq!(later.diff_days_f64(start), -0.0625);
+    }
+
+    #[test]
+    fn seconds_of_day_utc() {
+        // 2018-12-18T12:32:22.5Z
+        assert_eq!(Seconds(1_545_136_342.5).seconds_of_day_utc(), 45_142.5);
+        assert_eq!(Seconds(-1.0).seconds_of_day_utc(), 86_399.0);
+        assert_eq!(
+            Seconds(-86_400.0 * 3.0 - 0.5).seconds_of_day_utc(),
+            86_399.5
+        );
+        assert_eq!(Seconds(86_400.0).seconds_of_day_utc(), 0.0);
+        assert_eq!(Seconds(-1e-20).seconds_of_day_utc(), 0.0);
+    }
+
+    #[test]
+    fn seconds_from_millis_slice() {
+        assert_eq!(
+            Seconds::from_millis_slice(&[1_545_136_342_711, 0, -1_500]),
+            vec![Seconds(1_545_136_342.711), Seconds::EPOCH, Seconds(-1.5)]
+        );
+        assert!(Seconds::from_millis_slice(&[]).is_empty());
+        assert_eq!(
+            Seconds::from_millis_iter(vec![1_000, 2_500]).collect::<Vec<_>>(),
+            vec![Seconds(1.0), Seconds(2.5)]
+        );
+    }
+
+    #[test]
+    fn seconds_approx_eq_ulps() {
         let secs = Seconds(1_545_136_342.711_932);
+        let next = Seconds(f64::from_bits(secs.0.to_bits() + 1));
+        assert!(secs.approx_eq_ulps(next, 1));
+        assert!(!secs.approx_eq_ulps(next, 0));
+        assert!(next.approx_eq_ulps(secs, 1));
+        assert!(secs.approx_eq_ulps(secs, 0));
+        assert!(Seconds(0.0).approx_eq_ulps(Seconds(-0.0), 0));
+        let tiny = Seconds(f64::from_bits(1));
+        assert!(tiny.approx_eq_ulps(Seconds(-tiny.0), 2));
+        assert!(!tiny.approx_eq_ulps(Seconds(-tiny.0), 1));
+        assert!(!Seconds(f64::NAN).approx_eq_ulps(Seconds(f64::NAN), u32::MAX));
+    }
+
+    #[test]
+    fn seconds_relative_parts() {
+        let expected = RelativeParts {
+            weeks: 0,
+            days: 1,
+            hours: 1,
+            minutes: 1,
+            seconds: 1,
+            subsec_nanos: 500_000_000,
+        };
+        assert_eq!(Seconds(90_061.5).relative_parts(), expected);
+        assert_eq!(Seconds(-90_061.5).relative_parts(), expected);
         assert_eq!(
-            secs + Duration::from_secs(1),
-            Seconds(1_545_136_343.711_932)
+            Seconds(2.0 * 604_800.0 + 3.0 * 86_400.0 + 4.0 * 3_600.0).relative_parts(),
+            RelativeParts {
+                weeks: 2,
+                days: 3,
+                hours: 4,
+                minutes: 0,
+                seconds: 0,
+                subsec_nanos: 0,
+            }
+        );
+        assert_eq!(
+            Seconds(f64::NAN).relative_parts(),
+            Seconds::EPOCH.relative_parts()
         );
     }
 
     #[test]
-    fn seconds_sub_duration() {
+    fn seconds_fits_in_f64_integer() {
+        assert!(Seconds(1_545_136_342.711_932).fits_in_f64_integer());
+        assert!(Seconds::MAX.fits_in_f64_integer());
+        assert!(Seconds::MIN.fits_in_f64_integer());
+        assert!(!Seconds(1e16).fits_in_f64_integer());
+        assert!(!Seconds(-1e16).fits_in_f64_integer());
+        assert!(!Seconds(f64::NAN).fits_in_f64_integer());
+    }
+
+    #[test]
+    fn seconds_clamp_to_i53() {
+        assert_eq!(Seconds(1.5).clamp_to_i53(), Seconds(1.5));
+        assert_eq!(Seconds(1e16).clamp_to_i53(), Seconds::MAX);
+        assert_eq!(Seconds(f64::NEG_INFINITY).clamp_to_i53(), Seconds::MIN);
+        assert!(Seconds(f64::NAN).clamp_to_i53().is_nan());
+    }
+
+    #[test]
+    fn seconds_from_bytes_auto() {
+        assert_eq!(
+            Seconds::from_bytes_auto(&1_545_136_342u32.to_be_bytes()),
+            Ok(Seconds(1_545_136_342.0))
+        );
         let secs = Seconds(1_545_136_342.711_932);
+        assert_eq!(Seconds::from_bytes_auto(&secs.to_be_bytes()), Ok(secs));
+        let err = Seconds::from_bytes_auto(&[0; 6]).unwrap_err();
+        assert_eq!(err.to_string(), "expected 4 or 8 bytes, got 6");
+        assert!(Seconds::from_bytes_auto(&[]).is_err());
+    }
+
+    #[test]
+    fn seconds_assert_within() {
+        assert_seconds_within!(Seconds(10.5), Seconds(10.0), Duration::from_millis(500));
+        assert_seconds_within!(Seconds(9.5), Seconds(10.0), Duration::from_millis(500),);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "assertion failed: seconds not within 500ms\n  actual: 11\nexpected: 10\n    diff: 1s"
+    )]
+    fn seconds_assert_within_fails() {
+        assert_seconds_within!(Seconds(11.0), Seconds(10.0), Duration::from_millis(500));
+    }
+
+    #[test]
+    #[should_panic(expected = "seconds not within")]
+    fn seconds_assert_within_fails_on_nan() {
+        assert_seconds_within!(Seconds(f64::NAN), Seconds(10.0), Duration::from_secs(1));
+    }
+
+    #[test]
+    fn seconds_now_truncated_to() {
+        let clock = FixedClock::new(Seconds(1_545_136_342.711_932));
         assert_eq!(
-            secs - Duration::from_secs(1),
-            Seconds(1_545_136_341.711_932)
+            Seconds::now_truncated_to_with(&clock, Duration::from_secs(1)),
+            Seconds(1_545_136_342.0)
         );
+        assert_eq!(
+            Seconds::now_truncated_to_with(&clock, Duration::from_secs(60)),
+            Seconds(1_545_136_320.0)
+        );
+        let now = Seconds::now_truncated_to(Duration::from_secs(60));
+        assert_eq!(now.0 % 60.0, 0.0);
     }
 
-    #[cfg(feature = "serde")]
     #[test]
-    fn seconds_serialize() {
+    fn seconds_elapsed_and_reset() {
+        let clock = FixedClock::new(Seconds(100.0));
+        let mut last_run = Seconds(90.0);
         assert_eq!(
-            serde_json::to_string(&Seconds(1_545_136_342.711_932)).expect("failed to serialize"),
-            "1545136342.711932"
+            last_run.elapsed_and_reset_with(&clock),
+            Duration::from_secs(10)
+        );
+        assert_eq!(last_run, Seconds(100.0));
+        clock.advance(Duration::from_millis(2500));
+        assert_eq!(
+            last_run.elapsed_and_reset_with(&clock),
+            Duration::from_millis(2500)
         );
+        assert_eq!(last_run, Seconds(102.5));
+        clock.set(Seconds(50.0));
+        assert_eq!(last_run.elapsed_and_reset_with(&clock), Duration::ZERO);
+        assert_eq!(last_run, Seconds(50.0));
     }
 
-    #[cfg(feature = "serde")]
     #[test]
-    fn seconds_deserialize_floats() {
+    fn seconds_saturating_add_signed_secs() {
+        assert_eq!(Seconds(10.0).saturating_add_signed_secs(2.5), Seconds(12.5));
+        assert_eq!(Seconds(1.0).saturating_add_signed_secs(-2.5), Seconds(-1.5));
         assert_eq!(
-            serde_json::from_slice::<Seconds>(b"1545136342.711932").expect("failed to serialize"),
-            Seconds(1_545_136_342.711_932)
+            Seconds(10.0).saturating_add_signed_secs(f64::NAN),
+            Seconds(10.0)
+        );
+        assert_eq!(
+            Seconds(10.0).saturating_add_signed_secs(f64::INFINITY),
+            Seconds::MAX
+        );
+        assert_eq!(
+            Seconds(10.0).saturating_add_signed_secs(-1e300),
+            Seconds::MIN
         );
     }
 
-    #[cfg(feature = "serde")]
     #[test]
-    fn seconds_fails_to_deserialize() {
-        match serde_json::from_slice::<Seconds>(b"{\"foo\":\"bar\"}") {
-            Err(err) => assert_eq!(
-                format!("{}", err),
-                "invalid type: map, expected floating point seconds at line 1 column 0"
-            ),
-            Ok(other) => panic!("unexpected result {}", other),
+    fn seconds_from_secs_in_range() {
+        // 1970 through 2100
+        let (lo, hi) = (Seconds::EPOCH, Seconds(4_102_444_800.0));
+        assert_eq!(
+            Seconds::from_secs_in_range(1_545_136_342.711, lo, hi),
+            Ok(Seconds(1_545_136_342.711))
+        );
+        assert_eq!(
+            Seconds::from_secs_in_range(1_545_136_342_711.0, lo, hi)
+                .unwrap_err()
+                .to_string(),
+            "1545136342711 seconds is outside of the range [0, 4102444800]"
+        );
+        assert!(Seconds::from_secs_in_range(-1.0, lo, hi).is_err());
+        assert!(Seconds::from_secs_in_range(f64::NAN, lo, hi).is_err());
+        assert_eq!(Seconds::from_secs_in_range(0.0, lo, hi), Ok(lo));
+    }
+
+    #[test]
+    fn seconds_as_secs_f64_rounded() {
+        assert_eq!(Seconds(1.23456).as_secs_f64_rounded(3), 1.235);
+        assert_eq!(Seconds(1.23456).as_secs_f64_rounded(0), 1.0);
+        assert_eq!(Seconds(-1.23456).as_secs_f64_rounded(2), -1.23);
+        assert_eq!(
+            Seconds(1_545_136_342.711_932).as_secs_f64_rounded(3),
+            1_545_136_342.712
+        );
+        assert_eq!(Seconds(1e300).as_secs_f64_rounded(20), 1e300);
+    }
+
+    #[test]
+    fn seconds_order_preserving_bytes() {
+        let values = [
+            f64::NEG_INFINITY,
+            -1e10,
+            -1.5,
+            -f64::MIN_POSITIVE,
+            -0.0,
+            0.0,
+            f64::MIN_POSITIVE,
+            1.5,
+            1_545_136_342.711_932,
+            f64::INFINITY,
+        ];
+        for a in &values {
+            for b in &values {
+                let (a, b) = (Seconds(*a), Seconds(*b));
+                assert_eq!(
+                    a.to_order_preserving_bytes()
+                        .cmp(&b.to_order_preserving_bytes()),
+                    a.0.total_cmp(&b.0),
+                    "{:?} vs {:?}",
+                    a,
+                    b
+                );
+            }
+            let secs = Seconds(*a);
+            let decoded = Seconds::from_order_preserving_bytes(secs.to_order_preserving_bytes());
+            assert_eq!(decoded.0.to_bits(), secs.0.to_bits());
         }
     }
+
+    #[test]
+    fn seconds_interpolate_index() {
+        let series = [Seconds(10.0), Seconds(20.0), Seconds(20.0), Seconds(40.0)];
+        assert_eq!(
+            Seconds::interpolate_index(Seconds(15.0), &series),
+            Some(0.5)
+        );
+        assert_eq!(
+            Seconds::interpolate_index(Seconds(30.0), &series),
+            Some(2.5)
+        );
+        assert_eq!(
+            Seconds::interpolate_index(Seconds(10.0), &series),
+            Some(0.0)
+        );
+        assert_eq!(
+            Seconds::interpolate_index(Seconds(20.0), &series),
+            Some(1.0)
+        );
+        assert_eq!(
+            Seconds::interpolate_index(Seconds(40.0), &series),
+            Some(3.0)
+        );
+        assert_eq!(Seconds::interpolate_index(Seconds(5.0), &series), None);
+        assert_eq!(Seconds::interpolate_index(Seconds(45.0), &series), None);
+        assert_eq!(Seconds::interpolate_index(Seconds(f64::NAN), &series), None);
+        assert_eq!(Seconds::interpolate_index(Seconds(10.0), &[]), None);
+    }
+
+    #[test]
+    fn seconds_estimate_period() {
+        let clean: Vec<_> = (0..5)
+            .map(|i| Seconds(100.0 + 5.0 * f64::from(i)))
+            .collect();
+        assert_eq!(
+            Seconds::estimate_period(&clean),
+            Some(Duration::from_secs(5))
+        );
+        let noisy = [
+            Seconds(100.0),
+            Seconds(105.0),
+            Seconds(110.0),
+            Seconds(140.0),
+            Seconds(145.0),
+            Seconds(150.0),
+        ];
+        assert_eq!(
+            Seconds::estimate_period(&noisy),
+            Some(Duration::from_secs(5))
+        );
+        assert_eq!(Seconds::estimate_period(&[Seconds(100.0)]), None);
+        assert_eq!(Seconds::estimate_period(&[]), None);
+        assert_eq!(
+            Seconds::estimate_period(&[Seconds(100.0), Seconds(f64::NAN)]),
+            None
+        );
+        let trailing_nan = [
+            Seconds(0.0),
+            Seconds(1.0),
+            Seconds(2.0),
+            Seconds(3.0),
+            Seconds(f64::NAN),
+        ];
+        assert_eq!(Seconds::estimate_period(&trailing_nan), None);
+    }
+
+    #[test]
+    fn seconds_percentile() {
+        let odd = [Seconds(10.0), Seconds(20.0), Seconds(40.0)];
+        assert_eq!(Seconds::percentile(&odd, 0.5), Some(Seconds(20.0)));
+        let even = [Seconds(10.0), Seconds(20.0), Seconds(40.0), Seconds(80.0)];
+        assert_eq!(Seconds::percentile(&even, 0.5), Some(Seconds(30.0)));
+        assert_eq!(Seconds::percentile(&even, 0.0), Some(Seconds(10.0)));
+        assert_eq!(Seconds::percentile(&even, 1.0), Some(Seconds(80.0)));
+        assert_eq!(Seconds::percentile(&even, -1.0), Some(Seconds(10.0)));
+        assert_eq!(Seconds::percentile(&even, 2.0), Some(Seconds(80.0)));
+        assert_eq!(
+            Seconds::percentile(&[Seconds(5.0)], 0.95),
+            Some(Seconds(5.0))
+        );
+        assert_eq!(Seconds::percentile(&[], 0.5), None);
+    }
+
+    #[test]
+    fn seconds_varint_delta() {
+        let base = Seconds(1_545_136_342.0);
+        let near = Seconds(1_545_136_342.025);
+        let bytes = near.to_varint_delta(base, 3);
+        assert_eq!(bytes, vec![50]);
+        assert_eq!(Seconds::from_varint_delta(&bytes, base, 3), Ok((near, 1)));
+
+        let earlier = Seconds(1_545_136_341.999);
+        assert_eq!(earlier.to_varint_delta(base, 3), vec![1]);
+
+        let far = Seconds(1_545_222_742.5);
+        let bytes = far.to_varint_delta(base, 3);
+        assert_eq!(bytes.len(), 4);
+        assert_eq!(Seconds::from_varint_delta(&bytes, base, 3), Ok((far, 4)));
+
+        // decoding stops at the end of the first varint
+        let mut column = near.to_varint_delta(base, 3);
+        column.extend(far.to_varint_delta(base, 3));
+        assert_eq!(Seconds::from_varint_delta(&column, base, 3), Ok((near, 1)));
+        assert_eq!(
+            Seconds::from_varint_delta(&column[1..], base, 3),
+            Ok((far, 4))
+        );
+    }
+
+    #[test]
+    fn seconds_varint_delta_extremes() {
+        let huge = Seconds(1e300).to_varint_delta(Seconds::EPOCH, 0);
+        assert_eq!(huge.len(), 10);
+        assert_eq!(
+            Seconds::from_varint_delta(&huge, Seconds::EPOCH, 0),
+            Ok((Seconds(i64::MAX as f64), 10))
+        );
+        let err = Seconds::from_varint_delta(&[0x80, 0x80], Seconds::EPOCH, 0).unwrap_err();
+        assert_eq!(err.to_string(), "truncated or overlong varint");
+        assert!(Seconds::from_varint_delta(&[], Seconds::EPOCH, 0).is_err());
+        assert!(Seconds::from_varint_delta(&[0xff; 11], Seconds::EPOCH, 0).is_err());
+    }
+
+    #[test]
+    fn seconds_rate() {
+        assert_eq!(Seconds::rate(50, Seconds(100.0), Seconds(110.0)), 5.0);
+        assert_eq!(Seconds::rate(50, Seconds(110.0), Seconds(100.0)), 5.0);
+        assert_eq!(Seconds::rate(0, Seconds(100.0), Seconds(110.0)), 0.0);
+        assert_eq!(Seconds::rate(0, Seconds(100.0), Seconds(100.0)), 0.0);
+        assert_eq!(
+            Seconds::rate(50, Seconds(100.0), Seconds(100.0)),
+            f64::INFINITY
+        );
+    }
+
+    #[test]
+    fn seconds_snap_to_grid() {
+        let (period, phase) = (Duration::from_secs(1800), Duration::from_secs(420));
+        // 12:20 snaps between the 12:07 and 12:37 ticks
+        let secs = Seconds(12.0 * 3600.0 + 20.0 * 60.0);
+        let (before, after) = (
+            Seconds(12.0 * 3600.0 + 420.0),
+            Seconds(12.0 * 3600.0 + 2220.0),
+        );
+        assert_eq!(
+            secs.snap_to_grid(period, phase, RoundingMode::Floor),
+            before
+        );
+        assert_eq!(secs.snap_to_grid(period, phase, RoundingMode::Ceil), after);
+        assert_eq!(
+            secs.snap_to_grid(period, phase, RoundingMode::Nearest),
+            before
+        );
+        assert_eq!(
+            before.snap_to_grid(period, phase, RoundingMode::Ceil),
+            before
+        );
+        // 12:05 is before the 12:07 tick
+        assert_eq!(
+            Seconds(12.0 * 3600.0 + 300.0).snap_to_grid(period, phase, RoundingMode::Floor),
+            Seconds(11.0 * 3600.0 + 2220.0)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "grid phase must be less than the period")]
+    fn seconds_snap_to_grid_invalid_phase() {
+        let _ = Seconds(10.0).snap_to_grid(
+            Duration::from_secs(60),
+            Duration::from_secs(60),
+            RoundingMode::Floor,
+        );
+    }
+
+    #[test]
+    fn seconds_spread() {
+        assert_eq!(
+            Seconds::spread(Seconds(0.0), Seconds(8.0), 4),
+            vec![Seconds(0.0), Seconds(2.0), Seconds(4.0), Seconds(6.0)]
+        );
+        assert_eq!(
+            Seconds::spread(Seconds(100.0), Seconds(101.0), 1),
+            vec![Seconds(100.0)]
+        );
+        assert!(Seconds::spread(Seconds(0.0), Seconds(8.0), 0).is_empty());
+        assert!(Seconds::spread(Seconds(8.0), Seconds(8.0), 4).is_empty());
+        assert!(Seconds::spread(Seconds(8.0), Seconds(0.0), 4).is_empty());
+    }
 }
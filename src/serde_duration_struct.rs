@@ -0,0 +1,105 @@
+//! Serialize and deserialize [`Seconds`](../struct.Seconds.html) as a
+//! `{"secs": .., "nanos": ..}` object, the same shape serde uses for
+//! `std::time::Duration`
+//!
+//! This eases migrating records which were stored by serializing a
+//! `Duration` since the epoch directly
+//!
+//! ```rust,ignore
+//! #[derive(Serialize, Deserialize)]
+//! struct Record {
+//!     #[serde(with = "unisecs::serde_duration_struct")]
+//!     created: unisecs::Seconds,
+//! }
+//! ```
+use crate::{fits_i64, Seconds};
+use serde::{ser, Deserialize, Deserializer, Serialize, Serializer};
+use std::time::Duration;
+
+/// serialize seconds as a `{"secs": .., "nanos": ..}` object
+///
+/// A `Duration` can not be negative, so seconds before the epoch fail to serialize
+pub fn serialize<S>(
+    seconds: &Seconds,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    if seconds.0 < 0.0 || !fits_i64(seconds.0.floor()) {
+        return Err(ser::Error::custom(format!(
+            "cannot serialize {} seconds as a duration since the epoch",
+            seconds
+        )));
+    }
+    let (secs, nanos) = seconds.to_timestamp_parts();
+    Duration::new(secs as u64, nanos as u32).serialize(serializer)
+}
+
+/// deserialize seconds from a `{"secs": .., "nanos": ..}` object
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Seconds, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Duration::deserialize(deserializer).map(Seconds::from_duration_since_epoch)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Seconds;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Record {
+        #[serde(with = "crate::serde_duration_struct")]
+        created: Seconds,
+    }
+
+    fn to_json(secs: f64) -> Result<String, serde_json::Error> {
+        serde_json::to_string(&Record {
+            created: Seconds(secs),
+        })
+    }
+
+    #[test]
+    fn serializes_as_object() {
+        assert_eq!(
+            to_json(1_545_136_342.5).expect("failed to serialize"),
+            r#"{"created":{"secs":1545136342,"nanos":500000000}}"#
+        );
+    }
+
+    #[test]
+    fn deserializes_from_object() {
+        let json = r#"{"created":{"secs":1545136342,"nanos":711932000}}"#;
+        assert_eq!(
+            serde_json::from_str::<Record>(json).expect("failed to deserialize"),
+            Record {
+                created: Seconds(1_545_136_342.711_932)
+            }
+        );
+    }
+
+    #[test]
+    fn round_trips() {
+        let json = to_json(1_545_136_342.711_932).expect("failed to serialize");
+        assert_eq!(
+            serde_json::from_str::<Record>(&json).expect("failed to deserialize"),
+            Record {
+                created: Seconds(1_545_136_342.711_932)
+            }
+        );
+    }
+
+    #[test]
+    fn rejects_pre_epoch() {
+        assert!(to_json(-1.5).is_err());
+    }
+
+    #[test]
+    fn rejects_unrepresentable_seconds() {
+        for &secs in &[f64::NAN, f64::INFINITY, 1e19, 1e300] {
+            assert!(to_json(secs).is_err(), "{}", secs);
+        }
+    }
+}
@@ -0,0 +1,193 @@
+//! Deserialize [`Seconds`](../struct.Seconds.html) from whichever shape a source
+//! happens to use, always serializing back as floating point seconds
+//!
+//! Accepted shapes are floating point or integer seconds, such as `1545136342.5`
+//! or `1545136342`, decimal seconds in a string, such as `"1545136342.5"`, and a
+//! `{"secs": .., "nanos": ..}` object, the shape serde uses for
+//! `std::time::Duration`. This requires a self describing format, such as JSON
+//!
+//! ```rust,ignore
+//! #[derive(Serialize, Deserialize)]
+//! struct Record {
+//!     #[serde(with = "unisecs::serde_flexible")]
+//!     created: unisecs::Seconds,
+//! }
+//! ```
+use crate::Seconds;
+use serde::{
+    de::{self, IgnoredAny, MapAccess, Visitor},
+    Deserializer, Serialize, Serializer,
+};
+use std::fmt;
+
+const NANOS_PER_SEC: u32 = 1_000_000_000;
+
+/// serialize seconds as floating point seconds
+pub fn serialize<S>(
+    seconds: &Seconds,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    seconds.serialize(serializer)
+}
+
+/// deserialize seconds from a number, a string or a `{"secs": .., "nanos": ..}` object
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Seconds, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_any(FlexibleVisitor)
+}
+
+struct FlexibleVisitor;
+
+impl<'de> Visitor<'de> for FlexibleVisitor {
+    type Value = Seconds;
+
+    fn expecting(
+        &self,
+        formatter: &mut fmt::Formatter,
+    ) -> fmt::Result {
+        formatter.write_str("seconds as a number, a string or a secs and nanos object")
+    }
+
+    fn visit_f64<E>(
+        self,
+        value: f64,
+    ) -> Result<Seconds, E>
+    where
+        E: de::Error,
+    {
+        Ok(Seconds(value))
+    }
+
+    fn visit_i64<E>(
+        self,
+        value: i64,
+    ) -> Result<Seconds, E>
+    where
+        E: de::Error,
+    {
+        Ok(Seconds(value as f64))
+    }
+
+    fn visit_u64<E>(
+        self,
+        value: u64,
+    ) -> Result<Seconds, E>
+    where
+        E: de::Error,
+    {
+        Ok(Seconds(value as f64))
+    }
+
+    fn visit_str<E>(
+        self,
+        value: &str,
+    ) -> Result<Seconds, E>
+    where
+        E: de::Error,
+    {
+        value.parse().map_err(E::custom)
+    }
+
+    fn visit_map<A>(
+        self,
+        mut map: A,
+    ) -> Result<Seconds, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let (mut secs, mut nanos) = (None, 0);
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "secs" => secs = Some(map.next_value::<i64>()?),
+                "nanos" => nanos = map.next_value::<u32>()?,
+                _ => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+        let secs = secs.ok_or_else(|| de::Error::missing_field("secs"))?;
+        if nanos >= NANOS_PER_SEC {
+            return Err(de::Error::custom(format!(
+                "nanos must be less than {}, got {}",
+                NANOS_PER_SEC, nanos
+            )));
+        }
+        Ok(Seconds::from_timespec_parts(secs, nanos))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Seconds;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Event {
+        #[serde(with = "crate::serde_flexible")]
+        at: Seconds,
+    }
+
+    fn from_json(at: &str) -> Result<Seconds, serde_json::Error> {
+        serde_json::from_str::<Event>(&format!(r#"{{"at":{}}}"#, at)).map(|event| event.at)
+    }
+
+    #[test]
+    fn serializes_as_float() {
+        let event = Event {
+            at: Seconds(1_545_136_342.5),
+        };
+        assert_eq!(
+            serde_json::to_string(&event).expect("failed to serialize"),
+            r#"{"at":1545136342.5}"#
+        );
+    }
+
+    #[test]
+    fn deserializes_every_shape() {
+        let secs = Seconds(1_545_136_342.5);
+        for json in &[
+            "1545136342.5",
+            r#""1545136342.5""#,
+            r#"{"secs":1545136342,"nanos":500000000}"#,
+            r#"{"nanos":500000000,"secs":1545136342,"source":"legacy"}"#,
+        ] {
+            assert_eq!(
+                from_json(json).expect("failed to deserialize"),
+                secs,
+                "{}",
+                json
+            );
+        }
+        assert_eq!(
+            from_json("1545136342").expect("failed to deserialize"),
+            Seconds(1_545_136_342.0)
+        );
+        assert_eq!(
+            from_json("-1").expect("failed to deserialize"),
+            Seconds(-1.0)
+        );
+        assert_eq!(
+            from_json(r#"{"secs":1545136342}"#).expect("failed to deserialize"),
+            Seconds(1_545_136_342.0)
+        );
+    }
+
+    #[test]
+    fn rejects_other_shapes() {
+        for json in &[
+            "true",
+            "null",
+            "[1545136342,0]",
+            r#""yesterday""#,
+            r#"{"nanos":5}"#,
+            r#"{"secs":1,"nanos":1000000000}"#,
+        ] {
+            assert!(from_json(json).is_err(), "{}", json);
+        }
+    }
+}
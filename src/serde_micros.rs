@@ -0,0 +1,104 @@
+//! Serialize and deserialize [`Seconds`](../struct.Seconds.html) as an integer number
+//! of microseconds since the unix epoch
+//!
+//! Values are rounded to the nearest microsecond with ties, such as a value exactly
+//! half way between two microseconds, rounding away from zero
+//!
+//! Note that JavaScript consumers parse JSON numbers as doubles, which are only
+//! exact up to 2^53 - 1. Epoch microseconds exceed that after the year 2255, unlike
+//! epoch milliseconds, see
+//! [`Seconds::is_js_safe_millis`](../struct.Seconds.html#method.is_js_safe_millis)
+//!
+//! ```rust,ignore
+//! #[derive(Serialize, Deserialize)]
+//! struct Event {
+//!     #[serde(with = "unisecs::serde_micros")]
+//!     at: unisecs::Seconds,
+//! }
+//! ```
+use crate::{fits_i64, Seconds};
+use serde::{ser, Deserialize, Deserializer, Serializer};
+
+/// serialize seconds as integer microseconds
+///
+/// Non-finite seconds, and those beyond an `i64` of microseconds, some 292,000
+/// years either side of the epoch, fail to serialize
+pub fn serialize<S>(
+    seconds: &Seconds,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let micros = (seconds.0 * 1.0e6).round();
+    if !fits_i64(micros) {
+        return Err(ser::Error::custom(format!(
+            "cannot serialize {} seconds as integer microseconds",
+            seconds
+        )));
+    }
+    serializer.serialize_i64(micros as i64)
+}
+
+/// deserialize seconds from integer microseconds
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Seconds, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    i64::deserialize(deserializer).map(|micros| Seconds::from_fixed_point(micros, 6))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Seconds;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Event {
+        #[serde(with = "crate::serde_micros")]
+        at: Seconds,
+    }
+
+    fn to_json(secs: f64) -> Result<String, serde_json::Error> {
+        serde_json::to_string(&Event { at: Seconds(secs) })
+    }
+
+    #[test]
+    fn serializes_as_integer() {
+        assert_eq!(
+            to_json(1_545_136_342.711_932).expect("failed to serialize"),
+            r#"{"at":1545136342711932}"#
+        );
+    }
+
+    #[test]
+    fn rounds_half_micros_away_from_zero() {
+        assert_eq!(to_json(2.5e-6).expect("failed to serialize"), r#"{"at":3}"#);
+        assert_eq!(
+            to_json(-2.5e-6).expect("failed to serialize"),
+            r#"{"at":-3}"#
+        );
+    }
+
+    #[test]
+    fn round_trips() {
+        let json = to_json(1_545_136_342.711_932).expect("failed to serialize");
+        assert_eq!(
+            serde_json::from_str::<Event>(&json).expect("failed to deserialize"),
+            Event {
+                at: Seconds(1_545_136_342.711_932)
+            }
+        );
+        assert_eq!(
+            serde_json::from_str::<Event>(r#"{"at":-1500000}"#).expect("failed to deserialize"),
+            Event { at: Seconds(-1.5) }
+        );
+    }
+
+    #[test]
+    fn rejects_unrepresentable_seconds() {
+        for &secs in &[f64::NAN, f64::INFINITY, f64::NEG_INFINITY, 1e14] {
+            assert!(to_json(secs).is_err(), "{}", secs);
+        }
+    }
+}
@@ -0,0 +1,64 @@
+//! Deserialize `null` as [`Seconds::EPOCH`](../struct.Seconds.html#associatedconstant.EPOCH)
+//! for non-optional fields where `null` means "unknown time"
+//!
+//! By default deserializing `Seconds` from `null` is an error, and fields which may
+//! legitimately be absent are best represented as `Option<Seconds>`. This module
+//! suits schemas where that isn't an option. Seconds always serialize as floating
+//! point seconds, so an unknown time is written back as `0.0` rather than `null`
+//!
+//! ```rust,ignore
+//! #[derive(Serialize, Deserialize)]
+//! struct Device {
+//!     #[serde(with = "unisecs::serde_null_epoch")]
+//!     last_seen: unisecs::Seconds,
+//! }
+//! ```
+use crate::Seconds;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// serialize seconds as floating point seconds
+pub fn serialize<S>(
+    seconds: &Seconds,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    seconds.serialize(serializer)
+}
+
+/// deserialize floating point seconds, or `null` as the epoch
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Seconds, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(Option::<Seconds>::deserialize(deserializer)?.unwrap_or(Seconds::EPOCH))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{deserialize, serialize};
+    use crate::Seconds;
+    use serde::Deserialize;
+
+    #[test]
+    fn null_is_epoch() {
+        let secs = deserialize(&mut serde_json::Deserializer::from_str("null"));
+        assert_eq!(secs.expect("failed to deserialize"), Seconds::EPOCH);
+        let secs = deserialize(&mut serde_json::Deserializer::from_str("1545136342.5"));
+        assert_eq!(
+            secs.expect("failed to deserialize"),
+            Seconds(1_545_136_342.5)
+        );
+        let mut buf = Vec::new();
+        serialize(&Seconds::EPOCH, &mut serde_json::Serializer::new(&mut buf))
+            .expect("failed to serialize");
+        assert_eq!(buf, b"0.0");
+    }
+
+    #[test]
+    fn null_is_an_error_by_default() {
+        let secs = Seconds::deserialize(&mut serde_json::Deserializer::from_str("null"));
+        assert!(secs.is_err());
+    }
+}
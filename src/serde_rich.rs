@@ -0,0 +1,142 @@
+//! Serialize and deserialize [`Seconds`](../struct.Seconds.html) as an
+//! `{"epoch": .., "iso": ..}` object carrying both the unix seconds and an
+//! RFC 3339 timestamp, for APIs which should be easy to debug by eye
+//!
+//! Deserializing accepts an object with either field, preferring `epoch` when
+//! both are present. This requires both the `serde` and `date` features
+//!
+//! ```rust,ignore
+//! #[derive(Serialize, Deserialize)]
+//! struct Event {
+//!     #[serde(with = "unisecs::serde_rich")]
+//!     at: unisecs::Seconds,
+//! }
+//! ```
+use crate::Seconds;
+use serde::{
+    de::{self, IgnoredAny, MapAccess, Visitor},
+    ser::SerializeStruct,
+    Deserializer, Serializer,
+};
+use std::fmt;
+
+const FIELDS: &[&str] = &["epoch", "iso"];
+
+/// serialize seconds as an `{"epoch": .., "iso": ..}` object
+pub fn serialize<S>(
+    seconds: &Seconds,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let mut object = serializer.serialize_struct("Seconds", 2)?;
+    object.serialize_field("epoch", seconds)?;
+    object.serialize_field("iso", &seconds.to_rfc3339())?;
+    object.end()
+}
+
+/// deserialize seconds from an object with an `epoch` or `iso` field
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Seconds, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_struct("Seconds", FIELDS, RichVisitor)
+}
+
+struct RichVisitor;
+
+impl<'de> Visitor<'de> for RichVisitor {
+    type Value = Seconds;
+
+    fn expecting(
+        &self,
+        formatter: &mut fmt::Formatter,
+    ) -> fmt::Result {
+        formatter.write_str("an object with an epoch or iso field")
+    }
+
+    fn visit_map<A>(
+        self,
+        mut map: A,
+    ) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let (mut epoch, mut iso) = (None, None);
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "epoch" => epoch = Some(map.next_value::<Seconds>()?),
+                "iso" => iso = Some(map.next_value::<String>()?),
+                _ => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+        match (epoch, iso) {
+            (Some(epoch), _) => Ok(epoch),
+            (None, Some(iso)) => Seconds::from_rfc3339(&iso).map_err(de::Error::custom),
+            (None, None) => Err(de::Error::missing_field("epoch")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Seconds;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Event {
+        #[serde(with = "crate::serde_rich")]
+        at: Seconds,
+    }
+
+    fn from_json(at: &str) -> Result<Seconds, serde_json::Error> {
+        serde_json::from_str::<Event>(&format!(r#"{{"at":{}}}"#, at)).map(|event| event.at)
+    }
+
+    #[test]
+    fn serializes_as_object() {
+        let event = Event {
+            at: Seconds(1_545_136_342.711_932),
+        };
+        assert_eq!(
+            serde_json::to_string(&event).expect("failed to serialize"),
+            r#"{"at":{"epoch":1545136342.711932,"iso":"2018-12-18T12:32:22.711932Z"}}"#
+        );
+    }
+
+    #[test]
+    fn deserializes_from_either_field() {
+        let secs = Seconds(1_545_136_342.711_932);
+        assert_eq!(
+            from_json(r#"{"epoch":1545136342.711932}"#).expect("failed to deserialize"),
+            secs
+        );
+        assert_eq!(
+            from_json(r#"{"iso":"2018-12-18T12:32:22.711932Z"}"#).expect("failed to deserialize"),
+            secs
+        );
+        let json = serde_json::to_string(&Event { at: secs }).expect("failed to serialize");
+        assert_eq!(
+            serde_json::from_str::<Event>(&json).expect("failed to deserialize"),
+            Event { at: secs }
+        );
+        assert_eq!(
+            from_json(r#"{"epoch":1.0,"iso":"2018-12-18T12:32:22Z","tz":"UTC"}"#)
+                .expect("failed to deserialize"),
+            Seconds(1.0)
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_objects() {
+        assert_eq!(
+            from_json("{}").unwrap_err().to_string(),
+            "missing field `epoch` at line 1 column 8"
+        );
+        assert!(from_json(r#"{"iso":"yesterday"}"#).is_err());
+        assert!(from_json("1545136342.711932").is_err());
+    }
+}
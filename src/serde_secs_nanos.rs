@@ -0,0 +1,111 @@
+//! Serialize and deserialize [`Seconds`](../struct.Seconds.html) as a two element
+//! `[seconds, nanos]` array of integers, the same shape as a protobuf `Timestamp`
+//!
+//! Whole seconds are floored so that nanos always fall within `[0, 1_000_000_000)`
+//!
+//! ```rust,ignore
+//! #[derive(Serialize, Deserialize)]
+//! struct Event {
+//!     #[serde(with = "unisecs::serde_secs_nanos")]
+//!     at: unisecs::Seconds,
+//! }
+//! ```
+use crate::{fits_i64, Seconds};
+use serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
+
+const NANOS_PER_SEC: i64 = 1_000_000_000;
+
+/// serialize seconds as a `[seconds, nanos]` array
+///
+/// Non-finite seconds, and those whose whole seconds don't fit in an `i64`, fail to
+/// serialize
+pub fn serialize<S>(
+    seconds: &Seconds,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    if !fits_i64(seconds.0.floor()) {
+        return Err(ser::Error::custom(format!(
+            "cannot serialize {} seconds as whole seconds and nanos",
+            seconds
+        )));
+    }
+    let (whole, nanos) = seconds.to_timestamp_parts();
+    (whole, nanos as u32).serialize(serializer)
+}
+
+/// deserialize seconds from a `[seconds, nanos]` array
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Seconds, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let (whole, nanos) = <(i64, u32)>::deserialize(deserializer)?;
+    if i64::from(nanos) >= NANOS_PER_SEC {
+        return Err(de::Error::custom(format!(
+            "nanos must be less than {}, got {}",
+            NANOS_PER_SEC, nanos
+        )));
+    }
+    Ok(Seconds::from_timestamp_parts(whole, nanos as i32))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Seconds;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Event {
+        #[serde(with = "crate::serde_secs_nanos")]
+        at: Seconds,
+    }
+
+    #[test]
+    fn serializes_as_array() {
+        let event = Event {
+            at: Seconds(1_545_136_342.5),
+        };
+        assert_eq!(
+            serde_json::to_string(&event).expect("failed to serialize"),
+            r#"{"at":[1545136342,500000000]}"#
+        );
+    }
+
+    #[test]
+    fn carries_rounded_nanos() {
+        let event = Event {
+            at: Seconds(1.999_999_999_9),
+        };
+        assert_eq!(
+            serde_json::to_string(&event).expect("failed to serialize"),
+            r#"{"at":[2,0]}"#
+        );
+    }
+
+    #[test]
+    fn round_trips() {
+        let event = Event {
+            at: Seconds(1_545_136_342.5),
+        };
+        let json = serde_json::to_string(&event).expect("failed to serialize");
+        assert_eq!(
+            serde_json::from_str::<Event>(&json).expect("failed to deserialize"),
+            event
+        );
+    }
+
+    #[test]
+    fn rejects_out_of_range_nanos() {
+        assert!(serde_json::from_str::<Event>(r#"{"at":[1,1000000000]}"#).is_err());
+    }
+
+    #[test]
+    fn rejects_unrepresentable_seconds() {
+        for &secs in &[f64::NAN, f64::INFINITY, f64::NEG_INFINITY, 1e19] {
+            let event = Event { at: Seconds(secs) };
+            assert!(serde_json::to_string(&event).is_err(), "{}", secs);
+        }
+    }
+}
@@ -0,0 +1,149 @@
+//! Serialize and deserialize `Option<Seconds>` using a sentinel number, such as
+//! `0` or `-1`, in place of `null` to represent "no timestamp"
+//!
+//! The [`zero`](zero/index.html) and [`negative_one`](negative_one/index.html)
+//! modules cover the most common sentinels and can be used directly with
+//! `#[serde(with = "...")]`
+//!
+//! ```rust,ignore
+//! #[derive(Serialize, Deserialize)]
+//! struct Job {
+//!     #[serde(with = "unisecs::serde_sentinel::zero")]
+//!     finished_at: Option<unisecs::Seconds>,
+//! }
+//! ```
+//!
+//! Other sentinels can be supported by wrapping
+//! [`serialize_with_sentinel`](fn.serialize_with_sentinel.html) and
+//! [`deserialize_with_sentinel`](fn.deserialize_with_sentinel.html) in your own
+//! functions for use with `serialize_with` and `deserialize_with`
+use crate::Seconds;
+use serde::{ser, Deserialize, Deserializer, Serialize, Serializer};
+
+/// serialize `None` as `sentinel` and `Some` seconds as their floating point value
+///
+/// `Some` seconds equal to `sentinel` fail to serialize, since they would be read
+/// back as `None`
+pub fn serialize_with_sentinel<S>(
+    value: &Option<Seconds>,
+    sentinel: i64,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match value {
+        Some(seconds) if seconds.0 == sentinel as f64 => Err(ser::Error::custom(format!(
+            "cannot serialize {} seconds, which is the sentinel for no timestamp",
+            seconds
+        ))),
+        Some(seconds) => seconds.serialize(serializer),
+        None => serializer.serialize_i64(sentinel),
+    }
+}
+
+/// deserialize `sentinel` as `None` and any other number as `Some` seconds
+pub fn deserialize_with_sentinel<'de, D>(
+    sentinel: i64,
+    deserializer: D,
+) -> Result<Option<Seconds>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = f64::deserialize(deserializer)?;
+    if value == sentinel as f64 {
+        Ok(None)
+    } else {
+        Ok(Some(Seconds(value)))
+    }
+}
+
+macro_rules! sentinel {
+    ($(#[$meta:meta])* $name:ident, $sentinel:expr) => {
+        $(#[$meta])*
+        pub mod $name {
+            use crate::Seconds;
+            use serde::{Deserializer, Serializer};
+
+            /// serialize `None` as the sentinel
+            pub fn serialize<S>(
+                value: &Option<Seconds>,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                super::serialize_with_sentinel(value, $sentinel, serializer)
+            }
+
+            /// deserialize the sentinel as `None`
+            pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Seconds>, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                super::deserialize_with_sentinel($sentinel, deserializer)
+            }
+        }
+    };
+}
+
+sentinel!(
+    /// Treats `0` as "no timestamp"
+    zero,
+    0
+);
+
+sentinel!(
+    /// Treats `-1` as "no timestamp"
+    negative_one,
+    -1
+);
+
+#[cfg(test)]
+mod tests {
+    use super::{negative_one, zero};
+    use crate::Seconds;
+
+    #[test]
+    fn zero_sentinel() {
+        let mut buf = Vec::new();
+        zero::serialize(&None, &mut serde_json::Serializer::new(&mut buf))
+            .expect("failed to serialize");
+        assert_eq!(buf, b"0");
+        let none = zero::deserialize(&mut serde_json::Deserializer::from_str("0"));
+        assert_eq!(none.expect("failed to deserialize"), None);
+        let some = zero::deserialize(&mut serde_json::Deserializer::from_str("1545136342.5"));
+        assert_eq!(
+            some.expect("failed to deserialize"),
+            Some(Seconds(1_545_136_342.5))
+        );
+    }
+
+    #[test]
+    fn negative_one_sentinel() {
+        let mut buf = Vec::new();
+        negative_one::serialize(&None, &mut serde_json::Serializer::new(&mut buf))
+            .expect("failed to serialize");
+        assert_eq!(buf, b"-1");
+        let none = negative_one::deserialize(&mut serde_json::Deserializer::from_str("-1"));
+        assert_eq!(none.expect("failed to deserialize"), None);
+        let some = negative_one::deserialize(&mut serde_json::Deserializer::from_str("0"));
+        assert_eq!(some.expect("failed to deserialize"), Some(Seconds(0.0)));
+    }
+
+    #[test]
+    fn sentinel_seconds_fail_to_serialize() {
+        let mut buf = Vec::new();
+        assert!(zero::serialize(
+            &Some(Seconds(0.0)),
+            &mut serde_json::Serializer::new(&mut buf)
+        )
+        .is_err());
+        assert!(negative_one::serialize(
+            &Some(Seconds(-1.0)),
+            &mut serde_json::Serializer::new(&mut buf)
+        )
+        .is_err());
+        assert!(buf.is_empty());
+    }
+}
@@ -0,0 +1,71 @@
+//! Maps `Seconds` to the floating point column type of each sqlx database,
+//! `DOUBLE PRECISION` for PostgreSQL, `DOUBLE` for MySQL and `REAL` for SQLite
+//!
+//! Seconds encode, decode and type check exactly as an `f64` would, so they bind
+//! and decode anywhere sqlx accepts an `f64`. Nullable columns map to
+//! `Option<Seconds>` as usual
+use crate::Seconds;
+use sqlx::{encode::IsNull, error::BoxDynError, Database, Decode, Encode, Type};
+
+impl<DB> Type<DB> for Seconds
+where
+    DB: Database,
+    f64: Type<DB>,
+{
+    fn type_info() -> DB::TypeInfo {
+        <f64 as Type<DB>>::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <f64 as Type<DB>>::compatible(ty)
+    }
+}
+
+impl<'q, DB> Encode<'q, DB> for Seconds
+where
+    DB: Database,
+    f64: Encode<'q, DB>,
+{
+    fn encode_by_ref(
+        &self,
+        buf: &mut DB::ArgumentBuffer<'q>,
+    ) -> Result<IsNull, BoxDynError> {
+        self.0.encode_by_ref(buf)
+    }
+}
+
+impl<'r, DB> Decode<'r, DB> for Seconds
+where
+    DB: Database,
+    f64: Decode<'r, DB>,
+{
+    fn decode(value: DB::ValueRef<'r>) -> Result<Self, BoxDynError> {
+        f64::decode(value).map(Seconds)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Seconds;
+    use sqlx::sqlite::SqlitePool;
+
+    #[tokio::test]
+    async fn round_trips_through_sqlite() -> Result<(), sqlx::Error> {
+        let pool = SqlitePool::connect("sqlite::memory:").await?;
+        sqlx::query("CREATE TABLE events (at REAL NOT NULL, finished_at REAL)")
+            .execute(&pool)
+            .await?;
+        sqlx::query("INSERT INTO events (at, finished_at) VALUES (?, ?)")
+            .bind(Seconds(1_545_136_342.711_932))
+            .bind(None::<Seconds>)
+            .execute(&pool)
+            .await?;
+        let (at, finished_at): (Seconds, Option<Seconds>) =
+            sqlx::query_as("SELECT at, finished_at FROM events")
+                .fetch_one(&pool)
+                .await?;
+        assert_eq!(at, Seconds(1_545_136_342.711_932));
+        assert_eq!(finished_at, None);
+        Ok(())
+    }
+}
@@ -0,0 +1,217 @@
+//! Helpers for processing streams of timestamps
+use crate::Seconds;
+use std::time::Duration;
+
+/// Enforces a non-decreasing sequence of timestamps from a source which may
+/// momentarily step backward, such as a clock adjusted for skew
+///
+/// Each candidate earlier than the last emitted timestamp is clamped up to it
+#[derive(Debug, Default, PartialEq, Copy, Clone)]
+pub struct MonotonicClamp {
+    last: Option<Seconds>,
+}
+
+impl MonotonicClamp {
+    /// create a clamp which has not yet emitted any timestamps
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// return the later of `candidate` and the last emitted timestamp, remembering
+    /// it for the next call
+    ///
+    /// `NaN` candidates are replaced by the last emitted timestamp, when there is one
+    pub fn next(
+        &mut self,
+        candidate: Seconds,
+    ) -> Seconds {
+        let emitted = match self.last {
+            Some(last) if candidate.0 < last.0 || candidate.is_nan() => last,
+            _ => candidate,
+        };
+        self.last = Some(emitted);
+        emitted
+    }
+
+    /// return the last emitted timestamp, if any
+    #[must_use]
+    pub fn last(&self) -> Option<Seconds> {
+        self.last
+    }
+}
+
+/// Enforces a non-decreasing sequence of timestamps which also never jumps
+/// further forward than a maximum step, guarding against both clock skew and
+/// corrupt values from the far future
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct DriftClamp {
+    last: Seconds,
+    max_forward: Duration,
+}
+
+impl DriftClamp {
+    /// create a clamp starting from `last` which allows steps of up to `max_forward`
+    ///
+    /// A `NaN` `last` has nothing to clamp against, so it is replaced by the first
+    /// finite candidate
+    pub fn new(
+        last: Seconds,
+        max_forward: Duration,
+    ) -> Self {
+        DriftClamp { last, max_forward }
+    }
+
+    /// return `candidate` clamped to within `[last, last + max_forward]`,
+    /// remembering it for the next call
+    ///
+    /// `NaN` candidates are replaced by the last emitted timestamp
+    pub fn next(
+        &mut self,
+        candidate: Seconds,
+    ) -> Seconds {
+        if self.last.is_nan() {
+            if candidate.is_finite() {
+                self.last = candidate;
+            }
+            return self.last;
+        }
+        let ceiling = self.last.0 + self.max_forward.as_secs_f64();
+        if candidate.0 > ceiling {
+            self.last = Seconds(ceiling);
+        } else if candidate.0 > self.last.0 {
+            self.last = candidate;
+        }
+        self.last
+    }
+
+    /// return the last emitted timestamp
+    // Seconds is already #[must_use], but this matches the sibling accessors
+    #[must_use]
+    #[allow(clippy::double_must_use)]
+    pub fn last(&self) -> Seconds {
+        self.last
+    }
+}
+
+/// Collapses bursts of near-duplicate timestamps, passing through only those
+/// more than a tolerance past the last one passed through
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct Coalescer {
+    last: Option<Seconds>,
+    tolerance: Duration,
+}
+
+impl Coalescer {
+    /// create a coalescer which has not yet passed through any timestamps
+    pub fn new(tolerance: Duration) -> Self {
+        Coalescer {
+            last: None,
+            tolerance,
+        }
+    }
+
+    /// return `candidate`, remembering it for the next call, if it is the first or
+    /// is more than the tolerance past the last timestamp passed through, otherwise
+    /// `None`
+    ///
+    /// `NaN` candidates are never passed through
+    #[must_use]
+    pub fn accept(
+        &mut self,
+        candidate: Seconds,
+    ) -> Option<Seconds> {
+        let spaced = match self.last {
+            Some(last) => candidate.0 - last.0 > self.tolerance.as_secs_f64(),
+            None => !candidate.is_nan(),
+        };
+        if spaced {
+            self.last = Some(candidate);
+            Some(candidate)
+        } else {
+            None
+        }
+    }
+
+    /// return the last timestamp passed through, if any
+    #[must_use]
+    pub fn last(&self) -> Option<Seconds> {
+        self.last
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Coalescer, DriftClamp, MonotonicClamp};
+    use crate::Seconds;
+    use std::time::Duration;
+
+    #[test]
+    fn monotonic_clamp_never_decreases() {
+        let mut clamp = MonotonicClamp::new();
+        let output: Vec<_> = [1.0, 2.0, 1.5, 3.0, 2.9, 2.9, 4.0]
+            .iter()
+            .map(|&secs| clamp.next(Seconds(secs)))
+            .collect();
+        assert_eq!(
+            output,
+            vec![
+                Seconds(1.0),
+                Seconds(2.0),
+                Seconds(2.0),
+                Seconds(3.0),
+                Seconds(3.0),
+                Seconds(3.0),
+                Seconds(4.0),
+            ]
+        );
+        assert!(output.windows(2).all(|pair| pair[0].0 <= pair[1].0));
+        assert_eq!(clamp.last(), Some(Seconds(4.0)));
+    }
+
+    #[test]
+    fn monotonic_clamp_skips_nan() {
+        let mut clamp = MonotonicClamp::new();
+        assert_eq!(clamp.last(), None);
+        assert_eq!(clamp.next(Seconds(1.0)), Seconds(1.0));
+        assert_eq!(clamp.next(Seconds(f64::NAN)), Seconds(1.0));
+        assert_eq!(clamp.next(Seconds(2.0)), Seconds(2.0));
+    }
+
+    #[test]
+    fn drift_clamp_bounds_steps() {
+        let mut clamp = DriftClamp::new(Seconds(100.0), Duration::from_secs(60));
+        // a backward step is clamped up
+        assert_eq!(clamp.next(Seconds(90.0)), Seconds(100.0));
+        // a normal step passes through
+        assert_eq!(clamp.next(Seconds(130.0)), Seconds(130.0));
+        // an absurd forward jump is clamped down
+        assert_eq!(clamp.next(Seconds(1e12)), Seconds(190.0));
+        assert_eq!(clamp.next(Seconds(f64::NAN)), Seconds(190.0));
+        assert_eq!(clamp.last(), Seconds(190.0));
+    }
+
+    #[test]
+    fn drift_clamp_replaces_nan_last() {
+        let mut clamp = DriftClamp::new(Seconds(f64::NAN), Duration::from_secs(60));
+        assert!(clamp.next(Seconds(f64::INFINITY)).is_nan());
+        assert_eq!(clamp.next(Seconds(100.0)), Seconds(100.0));
+        assert_eq!(clamp.next(Seconds(90.0)), Seconds(100.0));
+        assert_eq!(clamp.next(Seconds(1e12)), Seconds(160.0));
+    }
+
+    #[test]
+    fn coalescer_collapses_bursts() {
+        let mut coalescer = Coalescer::new(Duration::from_millis(100));
+        assert_eq!(coalescer.last(), None);
+        let passed: Vec<_> = [1.0, 1.01, 1.05, 1.09, 1.2, 1.25, 2.0, f64::NAN, 2.05, 0.5]
+            .iter()
+            .filter_map(|&secs| coalescer.accept(Seconds(secs)))
+            .collect();
+        assert_eq!(passed, vec![Seconds(1.0), Seconds(1.2), Seconds(2.0)]);
+        assert_eq!(coalescer.last(), Some(Seconds(2.0)));
+        assert_eq!(
+            Coalescer::new(Duration::from_secs(1)).accept(Seconds(f64::NAN)),
+            None
+        );
+    }
+}